@@ -1,14 +1,17 @@
 use actix_web::client;
 use actix_web::Error;
-use block_kit::api::chat::PostMessageResponse;
-use block_kit::api::CHAT_POST_MESSAGE_URL;
+use block_kit::api::response::ApiResponse;
+use block_kit::api::{CHAT_POST_MESSAGE_URL, VIEWS_OPEN_URL};
 use block_kit::block::actions::ActionsBlock;
 use block_kit::block::context::ContextBlock;
 use block_kit::block::context::ContextBlockElement::{ImageContext, TextContext};
+use block_kit::block::input::InputBlock;
+use block_kit::block::input::InputBlockElement::PlainTextInput;
 use block_kit::block::section::SectionBlock;
 use block_kit::block_element::button::ButtonElement;
 use block_kit::block_element::button::Style::{Danger, Primary};
 use block_kit::block_element::image::ImageElement;
+use block_kit::block_element::plain_text_input::PlainTextInputElement;
 use block_kit::block_element::select_menu::StaticSelectMenuElement;
 use block_kit::block_element::BlockElement::{Button, StaticSelectMenu};
 use block_kit::composition::confirmation_dialog::ConfirmationDialog;
@@ -19,7 +22,10 @@ use block_kit::payload::chat::ChatPostMessagePayload;
 use block_kit::payload::interaction::InteractiveRespondPayload;
 use block_kit::payload::interaction::ResponseType::InChannel;
 use block_kit::payload::message::CommonMessagePayload;
+use block_kit::payload::views::ViewsOpenPayload;
+use block_kit::view::modal::ModalView;
 use serde::Serialize;
+use serde_json::Value;
 
 pub async fn send_static_select(config: &SlackConfig) -> Result<(), Error> {
     let section1 = SectionBlock::new(Markdown("*Hello World*".into()));
@@ -34,7 +40,7 @@ pub async fn send_static_select(config: &SlackConfig) -> Result<(), Error> {
         &config.channel,
         CommonMessagePayload::new().blocks(vec![section1.into(), section2.into()]),
     );
-    send(&payload, &config, None).await.unwrap_or(());
+    send(&payload, config, None).await.unwrap_or(());
     Ok(())
 }
 
@@ -50,7 +56,7 @@ pub async fn send_actions(config: &SlackConfig) -> Result<(), Error> {
         &config.channel,
         CommonMessagePayload::new().blocks(vec![actions.into()]),
     );
-    send(&payload, &config, None).await.unwrap_or(());
+    send(&payload, config, None).await.unwrap_or(());
 
     Ok(())
 }
@@ -70,7 +76,22 @@ pub async fn send_complete(config: &SlackConfig, response_url: &str) -> Result<(
         .replace_original(false)
         .response_type(InChannel);
 
-    send(&payload, &config, Some(response_url))
+    send(&payload, config, Some(response_url))
+        .await
+        .unwrap_or(());
+    Ok(())
+}
+
+pub async fn open_ticket_modal(config: &SlackConfig, trigger_id: &str) -> Result<(), Error> {
+    let input = InputBlock::new("Title", PlainTextInput(PlainTextInputElement::new("title")))
+        .block_id("title_block")
+        .optional(true);
+    let view = ModalView::new("New ticket", vec![input.into()])
+        .submit("Create")
+        .callback_id("new_ticket");
+
+    let payload = ViewsOpenPayload::new(trigger_id, view);
+    send(&payload, config, Some(VIEWS_OPEN_URL))
         .await
         .unwrap_or(());
     Ok(())
@@ -93,11 +114,11 @@ async fn send<T: Serialize>(
             format!("error: {:?}", err)
         })
         .unwrap()
-        .json::<PostMessageResponse>()
+        .json::<ApiResponse<Value>>()
         .await
-        .and_then(|response| {
-            info!("{:?}", response);
-            Ok(())
+        .map(|response| match response.result {
+            Ok(value) => info!("{:?}", value),
+            Err(err) => error!("{} {:?}", err, response.response_metadata),
         });
     Ok(())
 }
@@ -3,6 +3,7 @@
 use actix_web::client;
 use actix_web::Error;
 use block_kit::api::chat::PostMessageResponse;
+use block_kit::api::response::ApiResponse;
 use block_kit::api::CHAT_POST_MESSAGE_URL;
 use block_kit::block::actions::ActionsBlock;
 use block_kit::block::context::ContextBlock;
@@ -32,7 +33,7 @@ pub async fn send_hello_world(config: &SlackConfig) -> Result<(), Error> {
         .username("BOT");
 
     // Ex: reqwest
-    //    let res: PostMessageResponse = reqwest::Client::new()
+    //    let res: ApiResponse<PostMessageResponse> = reqwest::Client::new()
     //        .post(CHAT_POST_MESSAGE_URL)
     //        .header("Authorization", format!("Bearer {}", config.bot_token))
     //        .json(&payload)
@@ -55,11 +56,11 @@ pub async fn send_hello_world(config: &SlackConfig) -> Result<(), Error> {
             format!("error: {:?}", err)
         })
         .unwrap()
-        .json::<PostMessageResponse>()
+        .json::<ApiResponse<PostMessageResponse>>()
         .await
-        .and_then(|response| {
-            info!("{:?}", response);
-            Ok(())
+        .map(|response| match response.result {
+            Ok(message) => info!("{:?}", message),
+            Err(err) => error!("{} {:?}", err, response.response_metadata),
         });
     Ok(())
 }
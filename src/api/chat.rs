@@ -2,29 +2,33 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct ChatMessage {
+    #[serde(default)]
     pub text: String,
-    pub username: String,
-    pub bot_id: String,
+    /// Left out for messages posted with a bot token.
+    pub username: Option<String>,
+    pub bot_id: Option<String>,
     #[serde(rename = "type")]
     pub type_name: String,
-    pub subtype: String,
+    /// Left out for messages posted with a bot token.
+    pub subtype: Option<String>,
     pub ts: String,
 }
 
+/// Received in `ApiResponse`, which has `ok` and `error`.
+///
 /// Reference:
 /// https://api.slack.com/methods/chat.postMessage
 #[derive(Debug, Deserialize)]
 pub struct PostMessageResponse {
-    pub ok: bool,
-    pub error: Option<String>,
-    pub channel: Option<String>,
-    pub ts: Option<String>,
+    pub channel: String,
+    pub ts: String,
     pub message: Option<ChatMessage>,
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::api::response::{ApiError, ApiResponse};
 
     #[test]
     fn test_de_ok() {
@@ -41,17 +45,17 @@ mod test {
     "ts": "150"
   }
 }"#;
-        let res = serde_json::from_str::<PostMessageResponse>(json).unwrap();
-        assert!(res.ok);
-        assert_eq!(res.channel.unwrap(), "random");
-        assert_eq!(res.ts.unwrap(), "150");
+        let res = serde_json::from_str::<ApiResponse<PostMessageResponse>>(json).unwrap();
+        let res = res.into_result().unwrap();
+        assert_eq!(res.channel, "random");
+        assert_eq!(res.ts, "150");
 
         let message = res.message.unwrap();
         assert_eq!(message.text, "hello");
-        assert_eq!(message.username, "bot");
-        assert_eq!(message.bot_id, "id");
+        assert_eq!(message.username, Some("bot".to_string()));
+        assert_eq!(message.bot_id, Some("id".to_string()));
         assert_eq!(message.type_name, "message");
-        assert_eq!(message.subtype, "bot_message");
+        assert_eq!(message.subtype, Some("bot_message".to_string()));
         assert_eq!(message.ts, "150");
     }
 
@@ -61,8 +65,10 @@ mod test {
   "ok": false,
   "error": "error"
 }"#;
-        let res = serde_json::from_str::<PostMessageResponse>(json).unwrap();
-        assert!(!res.ok);
-        assert_eq!(res.error.unwrap(), "error");
+        let res = serde_json::from_str::<ApiResponse<PostMessageResponse>>(json).unwrap();
+        assert_eq!(
+            res.into_result().unwrap_err(),
+            ApiError::Other("error".to_string())
+        );
     }
 }
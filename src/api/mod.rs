@@ -1,4 +1,5 @@
 pub mod chat;
+pub mod response;
 
 macro_rules! base_url {
     () => {
@@ -6,4 +7,9 @@ macro_rules! base_url {
     };
 }
 
+pub const BASE_URL: &str = base_url!();
 pub const CHAT_POST_MESSAGE_URL: &str = concat!(base_url!(), "/chat.postMessage");
+pub const VIEWS_OPEN_URL: &str = concat!(base_url!(), "/views.open");
+pub const VIEWS_PUSH_URL: &str = concat!(base_url!(), "/views.push");
+pub const VIEWS_UPDATE_URL: &str = concat!(base_url!(), "/views.update");
+pub const VIEWS_PUBLISH_URL: &str = concat!(base_url!(), "/views.publish");
@@ -0,0 +1,235 @@
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer};
+use serde_json::{from_value, Value};
+use std::fmt;
+
+/// The envelope shared by all responses of the Web API.
+/// `result` has the fields of each method, like `PostMessageResponse`, when `ok` is true.
+///
+/// Reference:
+/// https://api.slack.com/web#responses
+///
+/// # Example:
+/// ```rust
+/// use block_kit::api::chat::PostMessageResponse;
+/// use block_kit::api::response::{ApiError, ApiResponse};
+///
+/// let json = r#"{
+///   "ok": false,
+///   "error": "invalid_blocks",
+///   "response_metadata": {
+///     "messages": ["[ERROR] must be more than 0 characters [json-pointer:/blocks/0/text/text]"]
+///   }
+/// }"#;
+/// let response = serde_json::from_str::<ApiResponse<PostMessageResponse>>(json).unwrap();
+/// assert_eq!(response.result.unwrap_err(), ApiError::InvalidBlocks);
+/// assert_eq!(response.response_metadata.unwrap().messages.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ApiResponse<T> {
+    pub result: Result<T, ApiError>,
+    /// Like `superfluous_charset`. The request succeeded, but should be fixed.
+    pub warning: Option<String>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl<T> ApiResponse<T> {
+    pub fn into_result(self) -> Result<T, ApiError> {
+        self.result
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponseMetadata {
+    /// Details of errors and warnings, like the position of `invalid_blocks`.
+    #[serde(default)]
+    pub messages: Vec<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    pub next_cursor: Option<String>,
+}
+
+/// Reference:
+/// https://api.slack.com/methods/chat.postMessage#errors
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    ChannelNotFound,
+    NotInChannel,
+    InvalidBlocks,
+    MsgTooLong,
+    Ratelimited,
+    InvalidAuth,
+    TokenRevoked,
+    MissingScope {
+        needed: Vec<String>,
+        provided: Vec<String>,
+    },
+    Other(String),
+}
+
+impl ApiError {
+    /// The `error` code of the response.
+    pub fn code(&self) -> &str {
+        match self {
+            ApiError::ChannelNotFound => "channel_not_found",
+            ApiError::NotInChannel => "not_in_channel",
+            ApiError::InvalidBlocks => "invalid_blocks",
+            ApiError::MsgTooLong => "msg_too_long",
+            ApiError::Ratelimited => "ratelimited",
+            ApiError::InvalidAuth => "invalid_auth",
+            ApiError::TokenRevoked => "token_revoked",
+            ApiError::MissingScope { .. } => "missing_scope",
+            ApiError::Other(code) => code,
+        }
+    }
+
+    fn new(code: String, needed: Option<String>, provided: Option<String>) -> Self {
+        match code.as_str() {
+            "channel_not_found" => ApiError::ChannelNotFound,
+            "not_in_channel" => ApiError::NotInChannel,
+            "invalid_blocks" => ApiError::InvalidBlocks,
+            "msg_too_long" => ApiError::MsgTooLong,
+            "ratelimited" => ApiError::Ratelimited,
+            "invalid_auth" => ApiError::InvalidAuth,
+            "token_revoked" => ApiError::TokenRevoked,
+            "missing_scope" => ApiError::MissingScope {
+                needed: scopes(needed),
+                provided: scopes(provided),
+            },
+            _ => ApiError::Other(code),
+        }
+    }
+}
+
+/// Splits comma separated scopes, like `identify,bot`.
+fn scopes(scopes: Option<String>) -> Vec<String> {
+    scopes
+        .iter()
+        .flat_map(|scopes| scopes.split(','))
+        .filter(|scope| !scope.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::MissingScope { needed, provided } => write!(
+                f,
+                "missing_scope: needed {}, but provided {}",
+                needed.join(","),
+                provided.join(",")
+            ),
+            error => write!(f, "{}", error.code()),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+#[derive(Deserialize)]
+struct Envelope {
+    ok: bool,
+    error: Option<String>,
+    needed: Option<String>,
+    provided: Option<String>,
+    warning: Option<String>,
+    response_metadata: Option<ResponseMetadata>,
+}
+
+impl<'de, T> Deserialize<'de> for ApiResponse<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let envelope = Envelope::deserialize(&value).map_err(D::Error::custom)?;
+        let result = if envelope.ok {
+            Ok(from_value(value).map_err(D::Error::custom)?)
+        } else {
+            let code = envelope.error.unwrap_or_default();
+            Err(ApiError::new(code, envelope.needed, envelope.provided))
+        };
+        Ok(ApiResponse {
+            result,
+            warning: envelope.warning,
+            response_metadata: envelope.response_metadata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Body {
+        channel: String,
+    }
+
+    #[test]
+    fn test_de_ok() {
+        let json = r#"{
+  "ok": true,
+  "channel": "C123",
+  "warning": "superfluous_charset",
+  "response_metadata": {
+    "warnings": ["superfluous_charset"]
+  }
+}"#;
+        let response = serde_json::from_str::<ApiResponse<Body>>(json).unwrap();
+        assert_eq!(response.warning, Some("superfluous_charset".to_string()));
+        assert_eq!(
+            response.response_metadata.unwrap().warnings,
+            vec!["superfluous_charset"]
+        );
+        assert_eq!(response.result.unwrap().channel, "C123");
+    }
+
+    #[test]
+    fn test_de_err() {
+        let cases = vec![
+            ("channel_not_found", ApiError::ChannelNotFound),
+            ("not_in_channel", ApiError::NotInChannel),
+            ("invalid_blocks", ApiError::InvalidBlocks),
+            ("msg_too_long", ApiError::MsgTooLong),
+            ("ratelimited", ApiError::Ratelimited),
+            ("invalid_auth", ApiError::InvalidAuth),
+            ("token_revoked", ApiError::TokenRevoked),
+            ("is_archived", ApiError::Other("is_archived".to_string())),
+        ];
+        for (code, expected) in cases {
+            let json = format!(r#"{{ "ok": false, "error": "{}" }}"#, code);
+            let response = serde_json::from_str::<ApiResponse<Body>>(&json).unwrap();
+            let error = response.into_result().unwrap_err();
+            assert_eq!(error.code(), code);
+            assert_eq!(error, expected);
+        }
+    }
+
+    #[test]
+    fn test_de_missing_scope() {
+        let json = r#"{
+  "ok": false,
+  "error": "missing_scope",
+  "needed": "chat:write",
+  "provided": "identify,bot"
+}"#;
+        let response = serde_json::from_str::<ApiResponse<Body>>(json).unwrap();
+        let error = response.into_result().unwrap_err();
+        assert_eq!(
+            error,
+            ApiError::MissingScope {
+                needed: vec!["chat:write".to_string()],
+                provided: vec!["identify".to_string(), "bot".to_string()],
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "missing_scope: needed chat:write, but provided identify,bot"
+        );
+    }
+}
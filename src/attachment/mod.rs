@@ -1,5 +1,6 @@
 use crate::attachment::color::Color;
 use crate::block::Block;
+use crate::validation::{field, Validate, Violation};
 use serde::Serialize;
 
 pub mod color;
@@ -28,6 +29,13 @@ impl Attachment {
     }
 }
 
+impl Validate for Attachment {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.blocks
+            .collect_violations(&field(path, "blocks"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
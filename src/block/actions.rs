@@ -1,11 +1,12 @@
 use crate::block::ACTIONS_TYPE;
 use crate::block_element::BlockElement;
-use serde::Serialize;
+use crate::validation::{check_count, check_optional_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActionsBlock {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     elements: Vec<BlockElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
     block_id: Option<String>,
@@ -14,7 +15,7 @@ pub struct ActionsBlock {
 impl ActionsBlock {
     pub fn new(elements: Vec<BlockElement>) -> Self {
         ActionsBlock {
-            type_name: ACTIONS_TYPE,
+            type_name: ACTIONS_TYPE.to_string(),
             elements,
             block_id: Option::default(),
         }
@@ -26,12 +27,23 @@ impl ActionsBlock {
     }
 }
 
+impl Validate for ActionsBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_count(field(path, "elements"), &self.elements, 25, violations);
+        self.elements
+            .collect_violations(&field(path, "elements"), violations);
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::block_element::button::ButtonElement;
     use crate::block_element::BlockElement::Button;
     use crate::composition::text::PlainText;
+    use crate::validation::Violation;
+    use crate::validation::ViolationKind::TooMany;
 
     #[test]
     fn test_ser_new() {
@@ -82,4 +94,34 @@ mod test {
 
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let actions = ActionsBlock::new(vec![Button(ButtonElement::new(
+            PlainText::new("text"),
+            "action_id",
+        ))])
+        .block_id("block");
+        let json = serde_json::to_string(&actions).unwrap();
+        let decoded = serde_json::from_str::<ActionsBlock>(&json).unwrap();
+        assert_eq!(decoded, actions);
+    }
+
+    #[test]
+    fn test_validate() {
+        let elements = (0..26)
+            .map(|i| Button(ButtonElement::new("text", format!("action_{}", i))))
+            .collect();
+        let actions = ActionsBlock::new(elements);
+        assert_eq!(
+            actions.validate(),
+            Err(vec![Violation {
+                path: "elements".to_string(),
+                kind: TooMany {
+                    max: 25,
+                    actual: 26
+                },
+            }])
+        );
+    }
 }
@@ -1,19 +1,41 @@
+use crate::block::context::ContextBlockElement::{ImageContext, TextContext};
 use crate::block::CONTEXT_TYPE;
 use crate::block_element::image::ImageElement;
-use crate::composition::text::Text;
-use serde::Serialize;
+use crate::block_element::IMAGE_TYPE;
+use crate::composition::text::{Text, MARKDOWN, PLAIN_TEXT};
+use crate::de::tagged;
+use crate::validation::{check_count, check_optional_length, field, Validate, Violation};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ContextBlockElement {
     ImageContext(ImageElement),
     TextContext(Text),
+    Unknown(Value),
 }
 
-#[derive(Debug, Serialize)]
+impl<'de> Deserialize<'de> for ContextBlockElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let element = match type_name.as_str() {
+            IMAGE_TYPE => from_value(value).map(ImageContext),
+            PLAIN_TEXT | MARKDOWN => from_value(value).map(TextContext),
+            _ => Ok(ContextBlockElement::Unknown(value)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContextBlock {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     elements: Vec<ContextBlockElement>,
     #[serde(skip_serializing_if = "Option::is_none")]
     block_id: Option<String>,
@@ -22,7 +44,7 @@ pub struct ContextBlock {
 impl ContextBlock {
     pub fn new(elements: Vec<ContextBlockElement>) -> Self {
         ContextBlock {
-            type_name: CONTEXT_TYPE,
+            type_name: CONTEXT_TYPE.to_string(),
             elements,
             block_id: Option::default(),
         }
@@ -34,10 +56,16 @@ impl ContextBlock {
     }
 }
 
+impl Validate for ContextBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_count(field(path, "elements"), &self.elements, 10, violations);
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::block::context::ContextBlockElement::{ImageContext, TextContext};
     use crate::composition::text::MarkdownText;
     use crate::composition::text::Text::Markdown;
 
@@ -64,4 +92,16 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let context = ContextBlock::new(vec![
+            TextContext(Markdown(MarkdownText::new("*markdown*"))),
+            ImageContext(ImageElement::new("image_url", "alt_text")),
+        ])
+        .block_id("block");
+        let json = serde_json::to_string(&context).unwrap();
+        let decoded = serde_json::from_str::<ContextBlock>(&json).unwrap();
+        assert_eq!(decoded, context);
+    }
 }
@@ -1,18 +1,25 @@
 use crate::block::DIVIDER_TYPE;
-use serde::Serialize;
+use crate::validation::{check_optional_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DividerBlock {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     block_id: Option<String>,
 }
 
+impl Default for DividerBlock {
+    fn default() -> Self {
+        DividerBlock::new()
+    }
+}
+
 impl DividerBlock {
     pub fn new() -> Self {
         DividerBlock {
-            type_name: DIVIDER_TYPE,
+            type_name: DIVIDER_TYPE.to_string(),
             block_id: Option::default(),
         }
     }
@@ -23,6 +30,12 @@ impl DividerBlock {
     }
 }
 
+impl Validate for DividerBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -49,4 +62,12 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let divider = DividerBlock::new().block_id("block");
+        let json = serde_json::to_string(&divider).unwrap();
+        let decoded = serde_json::from_str::<DividerBlock>(&json).unwrap();
+        assert_eq!(decoded, divider);
+    }
 }
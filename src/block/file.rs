@@ -0,0 +1,80 @@
+use crate::block::FILE_TYPE;
+use crate::validation::{check_optional_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+const REMOTE_SOURCE: &str = "remote";
+
+/// Displays a remote file, which is added by `files.remote.add`.
+///
+/// WARNING
+/// File blocks are currently only available in messages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileBlock {
+    #[serde(rename = "type")]
+    type_name: String,
+    external_id: String,
+    source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_id: Option<String>,
+}
+
+impl FileBlock {
+    /// `external_id` is the ID assigned to the remote file when it was added to Slack.
+    pub fn new(external_id: impl Into<String>) -> Self {
+        FileBlock {
+            type_name: FILE_TYPE.to_string(),
+            external_id: external_id.into(),
+            source: REMOTE_SOURCE.to_string(),
+            block_id: Option::default(),
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+impl Validate for FileBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_new() {
+        let file = FileBlock::new("external_id");
+        let json = serde_json::to_string_pretty(&file).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "file",
+  "external_id": "external_id",
+  "source": "remote"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_block_id() {
+        let file = FileBlock::new("external_id").block_id("block");
+        let json = serde_json::to_string_pretty(&file).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "file",
+  "external_id": "external_id",
+  "source": "remote",
+  "block_id": "block"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let file = FileBlock::new("external_id").block_id("block");
+        let json = serde_json::to_string(&file).unwrap();
+        let decoded = serde_json::from_str::<FileBlock>(&json).unwrap();
+        assert_eq!(decoded, file);
+    }
+}
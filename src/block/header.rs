@@ -0,0 +1,91 @@
+use crate::block::HEADER_TYPE;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{deserialize_plain, PlainText, Text};
+use crate::validation::{check_optional_length, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// A plain-text block that displays in a larger, bold font.
+///
+/// `text`: Maximum length is 150 characters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeaderBlock {
+    #[serde(rename = "type")]
+    type_name: String,
+    #[serde(deserialize_with = "deserialize_plain")]
+    text: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_id: Option<String>,
+}
+
+impl HeaderBlock {
+    pub fn new(text: impl Into<PlainText>) -> Self {
+        HeaderBlock {
+            type_name: HEADER_TYPE.to_string(),
+            text: Plain(text.into()),
+            block_id: Option::default(),
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+impl Validate for HeaderBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "text"), &self.text, 150, violations);
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_new() {
+        let header = HeaderBlock::new(PlainText::new("header"));
+        let json = serde_json::to_string_pretty(&header).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "header",
+  "text": {
+    "type": "plain_text",
+    "text": "header"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_block_id() {
+        let header = HeaderBlock::new("header").block_id("block");
+        let json = serde_json::to_string_pretty(&header).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "header",
+  "text": {
+    "type": "plain_text",
+    "text": "header"
+  },
+  "block_id": "block"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let header = HeaderBlock::new("header").block_id("block");
+        let json = serde_json::to_string(&header).unwrap();
+        let decoded = serde_json::from_str::<HeaderBlock>(&json).unwrap();
+        assert_eq!(decoded, header);
+    }
+
+    #[test]
+    fn test_de_markdown() {
+        let json = r#"{ "type": "header", "text": { "type": "mrkdwn", "text": "*header*" } }"#;
+        let error = serde_json::from_str::<HeaderBlock>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("invalid value: string \"mrkdwn\", expected plain_text"));
+    }
+}
@@ -1,12 +1,15 @@
 use crate::block::IMAGE_TYPE;
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{
+    check_length, check_optional_length, check_text, field, Validate, Violation,
+};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageBlock {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     image_url: String,
     alt_text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -18,7 +21,7 @@ pub struct ImageBlock {
 impl ImageBlock {
     pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
         ImageBlock {
-            type_name: IMAGE_TYPE,
+            type_name: IMAGE_TYPE.to_string(),
             image_url: image_url.into(),
             alt_text: alt_text.into(),
             title: Option::default(),
@@ -37,6 +40,17 @@ impl ImageBlock {
     }
 }
 
+impl Validate for ImageBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "image_url"), &self.image_url, 3000, violations);
+        check_length(field(path, "alt_text"), &self.alt_text, 2000, violations);
+        if let Some(title) = &self.title {
+            check_text(&field(path, "title"), title, 2000, violations);
+        }
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -100,4 +114,14 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let image = ImageBlock::new("url", "alt")
+            .title(PlainText::new("title"))
+            .block_id("id");
+        let json = serde_json::to_string(&image).unwrap();
+        let decoded = serde_json::from_str::<ImageBlock>(&json).unwrap();
+        assert_eq!(decoded, image);
+    }
 }
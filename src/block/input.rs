@@ -0,0 +1,325 @@
+use crate::block::input::InputBlockElement::{
+    ChannelsSelectMenu, Checkboxes, ConversationsSelectMenu, DatePicker, DateTimePicker,
+    EmailInput, ExternalSelectMenu, MultiChannelsSelectMenu, MultiConversationsSelectMenu,
+    MultiStaticSelectMenu, MultiUsersSelectMenu, NumberInput, PlainTextInput, RadioButtons,
+    StaticSelectMenu, TimePicker, Unknown, UrlInput, UsersSelectMenu,
+};
+use crate::block::INPUT_TYPE;
+use crate::block_element::checkboxes::CheckboxesElement;
+use crate::block_element::date_picker::DatePickerElement;
+use crate::block_element::date_time_picker::DateTimePickerElement;
+use crate::block_element::email_input::EmailInputElement;
+use crate::block_element::multi_select_menu::{
+    MultiChannelsSelectMenuElement, MultiConversationsSelectMenuElement,
+    MultiStaticSelectMenuElement, MultiUsersSelectMenuElement,
+};
+use crate::block_element::number_input::NumberInputElement;
+use crate::block_element::plain_text_input::PlainTextInputElement;
+use crate::block_element::radio_buttons::RadioButtonsElement;
+use crate::block_element::select_menu::{
+    ChannelsSelectMenuElement, ConversationsSelectMenuElement, ExternalSelectMenuElement,
+    StaticSelectMenuElement, UsersSelectMenuElement,
+};
+use crate::block_element::time_picker::TimePickerElement;
+use crate::block_element::url_input::UrlInputElement;
+use crate::block_element::{
+    CHANNELS_SELECT_MENU_TYPE, CHECKBOXES_TYPE, CONVERSATIONS_SELECT_MENU_TYPE, DATE_PICKER_TYPE,
+    DATE_TIME_PICKER_TYPE, EMAIL_TEXT_INPUT_TYPE, EXTERNAL_SELECT_MENU_TYPE,
+    MULTI_CHANNELS_SELECT_MENU_TYPE, MULTI_CONVERSATIONS_SELECT_MENU_TYPE,
+    MULTI_STATIC_SELECT_MENU_TYPE, MULTI_USERS_SELECT_MENU_TYPE, NUMBER_INPUT_TYPE,
+    PLAIN_TEXT_INPUT_TYPE, RADIO_BUTTONS_TYPE, STATIC_SELECT_MENU_TYPE, TIME_PICKER_TYPE,
+    URL_TEXT_INPUT_TYPE, USERS_SELECT_MENU_TYPE,
+};
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::de::tagged;
+use crate::validation::{check_optional_length, check_text, field, Validate, Violation};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
+
+/// Block elements which can be placed in an input block.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum InputBlockElement {
+    PlainTextInput(PlainTextInputElement),
+    EmailInput(EmailInputElement),
+    UrlInput(UrlInputElement),
+    NumberInput(NumberInputElement),
+    StaticSelectMenu(StaticSelectMenuElement),
+    ExternalSelectMenu(ExternalSelectMenuElement),
+    MultiStaticSelectMenu(MultiStaticSelectMenuElement),
+    UsersSelectMenu(UsersSelectMenuElement),
+    ConversationsSelectMenu(ConversationsSelectMenuElement),
+    ChannelsSelectMenu(ChannelsSelectMenuElement),
+    MultiUsersSelectMenu(MultiUsersSelectMenuElement),
+    MultiConversationsSelectMenu(MultiConversationsSelectMenuElement),
+    MultiChannelsSelectMenu(MultiChannelsSelectMenuElement),
+    DatePicker(DatePickerElement),
+    TimePicker(TimePickerElement),
+    DateTimePicker(DateTimePickerElement),
+    Checkboxes(CheckboxesElement),
+    RadioButtons(RadioButtonsElement),
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for InputBlockElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let element = match type_name.as_str() {
+            PLAIN_TEXT_INPUT_TYPE => from_value(value).map(PlainTextInput),
+            EMAIL_TEXT_INPUT_TYPE => from_value(value).map(EmailInput),
+            URL_TEXT_INPUT_TYPE => from_value(value).map(UrlInput),
+            NUMBER_INPUT_TYPE => from_value(value).map(NumberInput),
+            STATIC_SELECT_MENU_TYPE => from_value(value).map(StaticSelectMenu),
+            EXTERNAL_SELECT_MENU_TYPE => from_value(value).map(ExternalSelectMenu),
+            MULTI_STATIC_SELECT_MENU_TYPE => from_value(value).map(MultiStaticSelectMenu),
+            USERS_SELECT_MENU_TYPE => from_value(value).map(UsersSelectMenu),
+            CONVERSATIONS_SELECT_MENU_TYPE => from_value(value).map(ConversationsSelectMenu),
+            CHANNELS_SELECT_MENU_TYPE => from_value(value).map(ChannelsSelectMenu),
+            MULTI_USERS_SELECT_MENU_TYPE => from_value(value).map(MultiUsersSelectMenu),
+            MULTI_CONVERSATIONS_SELECT_MENU_TYPE => {
+                from_value(value).map(MultiConversationsSelectMenu)
+            }
+            MULTI_CHANNELS_SELECT_MENU_TYPE => from_value(value).map(MultiChannelsSelectMenu),
+            DATE_PICKER_TYPE => from_value(value).map(DatePicker),
+            TIME_PICKER_TYPE => from_value(value).map(TimePicker),
+            DATE_TIME_PICKER_TYPE => from_value(value).map(DateTimePicker),
+            CHECKBOXES_TYPE => from_value(value).map(Checkboxes),
+            RADIO_BUTTONS_TYPE => from_value(value).map(RadioButtons),
+            _ => Ok(Unknown(value)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+/// A block that collects information from users.
+///
+/// WARNING
+/// Input blocks are currently only available in modals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputBlock {
+    #[serde(rename = "type")]
+    type_name: String,
+    label: Text,
+    element: InputBlockElement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dispatch_action: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hint: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    optional: Option<bool>,
+}
+
+impl InputBlock {
+    pub fn new(label: impl Into<PlainText>, element: InputBlockElement) -> Self {
+        InputBlock {
+            type_name: INPUT_TYPE.to_string(),
+            label: Plain(label.into()),
+            element,
+            dispatch_action: Option::default(),
+            block_id: Option::default(),
+            hint: Option::default(),
+            optional: Option::default(),
+        }
+    }
+
+    /// If true, interacting with the element will send a `block_actions` payload.
+    pub fn dispatch_action(mut self, dispatch_action: bool) -> Self {
+        self.dispatch_action = Some(dispatch_action);
+        self
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Displayed below the input element.
+    pub fn hint(mut self, hint: impl Into<PlainText>) -> Self {
+        self.hint = Some(Plain(hint.into()));
+        self
+    }
+
+    /// If true, the input element may be empty when the modal is submitted.
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+}
+
+impl Validate for InputBlockElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        match self {
+            PlainTextInput(element) => element.collect_violations(path, violations),
+            EmailInput(element) => element.collect_violations(path, violations),
+            UrlInput(element) => element.collect_violations(path, violations),
+            NumberInput(element) => element.collect_violations(path, violations),
+            StaticSelectMenu(element) => element.collect_violations(path, violations),
+            ExternalSelectMenu(element) => element.collect_violations(path, violations),
+            MultiStaticSelectMenu(element) => element.collect_violations(path, violations),
+            UsersSelectMenu(element) => element.collect_violations(path, violations),
+            ConversationsSelectMenu(element) => element.collect_violations(path, violations),
+            ChannelsSelectMenu(element) => element.collect_violations(path, violations),
+            MultiUsersSelectMenu(element) => element.collect_violations(path, violations),
+            MultiConversationsSelectMenu(element) => element.collect_violations(path, violations),
+            MultiChannelsSelectMenu(element) => element.collect_violations(path, violations),
+            DatePicker(element) => element.collect_violations(path, violations),
+            TimePicker(element) => element.collect_violations(path, violations),
+            DateTimePicker(element) => element.collect_violations(path, violations),
+            Checkboxes(element) => element.collect_violations(path, violations),
+            RadioButtons(element) => element.collect_violations(path, violations),
+            Unknown(_) => {}
+        }
+    }
+}
+
+impl Validate for InputBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "label"), &self.label, 2000, violations);
+        self.element
+            .collect_violations(&field(path, "element"), violations);
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+        if let Some(hint) = &self.hint {
+            check_text(&field(path, "hint"), hint, 2000, violations);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::composition::option::OptionObject;
+
+    #[test]
+    fn test_ser_new() {
+        let input = InputBlock::new(
+            "label",
+            PlainTextInput(PlainTextInputElement::new("action_id")),
+        );
+        let json = serde_json::to_string_pretty(&input).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "input",
+  "label": {
+    "type": "plain_text",
+    "text": "label"
+  },
+  "element": {
+    "type": "plain_text_input",
+    "action_id": "action_id"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_static_select() {
+        let menu = StaticSelectMenuElement::new("placeholder", "action_id")
+            .options(vec![OptionObject::new("text", "value")]);
+        let input = InputBlock::new("label", StaticSelectMenu(menu));
+        let json = serde_json::to_string_pretty(&input).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "input",
+  "label": {
+    "type": "plain_text",
+    "text": "label"
+  },
+  "element": {
+    "type": "static_select",
+    "placeholder": {
+      "type": "plain_text",
+      "text": "placeholder"
+    },
+    "action_id": "action_id",
+    "options": [
+      {
+        "text": {
+          "type": "plain_text",
+          "text": "text"
+        },
+        "value": "value"
+      }
+    ]
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_hint() {
+        let input = InputBlock::new("label", DatePicker(DatePickerElement::new("action_id")))
+            .hint(PlainText::new("hint"));
+        let json = serde_json::to_string_pretty(&input).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "input",
+  "label": {
+    "type": "plain_text",
+    "text": "label"
+  },
+  "element": {
+    "type": "datepicker",
+    "action_id": "action_id"
+  },
+  "hint": {
+    "type": "plain_text",
+    "text": "hint"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_all() {
+        let input = InputBlock::new(
+            "label",
+            PlainTextInput(PlainTextInputElement::new("action_id")),
+        )
+        .dispatch_action(true)
+        .block_id("block")
+        .hint("hint")
+        .optional(true);
+        let json = serde_json::to_string_pretty(&input).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "input",
+  "label": {
+    "type": "plain_text",
+    "text": "label"
+  },
+  "element": {
+    "type": "plain_text_input",
+    "action_id": "action_id"
+  },
+  "dispatch_action": true,
+  "block_id": "block",
+  "hint": {
+    "type": "plain_text",
+    "text": "hint"
+  },
+  "optional": true
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = InputBlock::new(
+            "label",
+            MultiStaticSelectMenu(MultiStaticSelectMenuElement::new(
+                "placeholder",
+                "action_id",
+            )),
+        )
+        .dispatch_action(false)
+        .block_id("block")
+        .hint("hint")
+        .optional(true);
+        let json = serde_json::to_string(&input).unwrap();
+        let decoded = serde_json::from_str::<InputBlock>(&json).unwrap();
+        assert_eq!(decoded, input);
+    }
+}
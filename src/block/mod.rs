@@ -1,17 +1,33 @@
-use crate::block::Block::{Actions, Context, Divider, Image, Section};
-use serde::Serialize;
+use crate::block::Block::{
+    Actions, Context, Divider, File, Header, Image, Input, RichText, Section, Unknown, Video,
+};
+use crate::de::tagged;
+use crate::validation::{Validate, Violation};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
 
 pub mod actions;
 pub mod context;
 pub mod divider;
+pub mod file;
+pub mod header;
 pub mod image;
+pub mod input;
+pub mod rich_text;
 pub mod section;
+pub mod video;
 
-pub(self) const SECTION_TYPE: &str = "section";
-pub(self) const ACTIONS_TYPE: &str = "actions";
-pub(self) const CONTEXT_TYPE: &str = "context";
-pub(self) const DIVIDER_TYPE: &str = "divider";
-pub(self) const IMAGE_TYPE: &str = "image";
+const SECTION_TYPE: &str = "section";
+const ACTIONS_TYPE: &str = "actions";
+const CONTEXT_TYPE: &str = "context";
+const DIVIDER_TYPE: &str = "divider";
+const FILE_TYPE: &str = "file";
+const HEADER_TYPE: &str = "header";
+const IMAGE_TYPE: &str = "image";
+const INPUT_TYPE: &str = "input";
+const RICH_TEXT_TYPE: &str = "rich_text";
+const VIDEO_TYPE: &str = "video";
 
 /// Blocks are a series of components that can be combined to create visually rich and compellingly interactive messages.
 ///
@@ -20,20 +36,53 @@ pub(self) const IMAGE_TYPE: &str = "image";
 /// Context : Modals, Messages, Home tabs
 /// Divider : Modals, Messages, Home tabs
 /// File    : Messages
+/// Header  : Modals, Messages, Home tabs
 /// Image   : Modals, Messages, Home tabs
 /// Input   : Modals
+/// RichText: Modals, Messages, Home tabs
 /// Section : Modals, Messages, Home tabs
-#[derive(Debug, Serialize)]
+/// Video   : Modals, Messages, Home tabs
+///
+/// Deserializing chooses the variant by `type` of the block.
+/// Blocks of unknown `type`, like `call`, are kept as `Unknown` and serialized back unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum Block {
     Section(section::SectionBlock),
     Divider(divider::DividerBlock),
     Actions(actions::ActionsBlock),
     Image(image::ImageBlock),
     Context(context::ContextBlock),
-    /*    TODO:
-     *    File,
-     *    Input, */
+    Input(input::InputBlock),
+    File(file::FileBlock),
+    Header(header::HeaderBlock),
+    RichText(rich_text::RichTextBlock),
+    Video(video::VideoBlock),
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let block = match type_name.as_str() {
+            SECTION_TYPE => from_value(value).map(Section),
+            ACTIONS_TYPE => from_value(value).map(Actions),
+            CONTEXT_TYPE => from_value(value).map(Context),
+            DIVIDER_TYPE => from_value(value).map(Divider),
+            FILE_TYPE => from_value(value).map(File),
+            HEADER_TYPE => from_value(value).map(Header),
+            IMAGE_TYPE => from_value(value).map(Image),
+            INPUT_TYPE => from_value(value).map(Input),
+            RICH_TEXT_TYPE => from_value(value).map(RichText),
+            VIDEO_TYPE => from_value(value).map(Video),
+            _ => Ok(Unknown(value)),
+        };
+        block.map_err(D::Error::custom)
+    }
 }
 
 impl From<section::SectionBlock> for Block {
@@ -66,16 +115,76 @@ impl From<context::ContextBlock> for Block {
     }
 }
 
+impl From<input::InputBlock> for Block {
+    fn from(block: input::InputBlock) -> Self {
+        Input(block)
+    }
+}
+
+impl From<file::FileBlock> for Block {
+    fn from(block: file::FileBlock) -> Self {
+        File(block)
+    }
+}
+
+impl From<header::HeaderBlock> for Block {
+    fn from(block: header::HeaderBlock) -> Self {
+        Header(block)
+    }
+}
+
+impl From<rich_text::RichTextBlock> for Block {
+    fn from(block: rich_text::RichTextBlock) -> Self {
+        RichText(block)
+    }
+}
+
+impl From<video::VideoBlock> for Block {
+    fn from(block: video::VideoBlock) -> Self {
+        Video(block)
+    }
+}
+
+impl Validate for Block {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        match self {
+            Section(block) => block.collect_violations(path, violations),
+            Divider(block) => block.collect_violations(path, violations),
+            Actions(block) => block.collect_violations(path, violations),
+            Image(block) => block.collect_violations(path, violations),
+            Context(block) => block.collect_violations(path, violations),
+            Input(block) => block.collect_violations(path, violations),
+            File(block) => block.collect_violations(path, violations),
+            Header(block) => block.collect_violations(path, violations),
+            RichText(block) => block.collect_violations(path, violations),
+            Video(block) => block.collect_violations(path, violations),
+            Unknown(_) => {}
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::block::actions::ActionsBlock;
+    use crate::block::context::ContextBlock;
+    use crate::block::context::ContextBlockElement::TextContext;
     use crate::block::divider::DividerBlock;
+    use crate::block::file::FileBlock;
+    use crate::block::header::HeaderBlock;
+    use crate::block::image::ImageBlock;
+    use crate::block::input::InputBlock;
+    use crate::block::input::InputBlockElement::PlainTextInput;
+    use crate::block::rich_text::RichTextElement::Quote;
+    use crate::block::rich_text::RichTextInlineElement::Text;
+    use crate::block::rich_text::{RichTextBlock, RichTextQuote, TextElement};
     use crate::block::section::SectionBlock;
+    use crate::block::video::VideoBlock;
     use crate::block_element::button::ButtonElement;
+    use crate::block_element::plain_text_input::PlainTextInputElement;
     use crate::block_element::BlockElement::Button;
-    use crate::composition::text::PlainText;
-    use crate::composition::text::Text::Plain;
+    use crate::composition::text::Text::{Markdown, Plain};
+    use crate::composition::text::{MarkdownText, PlainText};
 
     #[test]
     fn test_ser_section() {
@@ -101,6 +210,20 @@ mod test {
         assert_eq!(json, expected);
     }
 
+    #[test]
+    fn test_ser_header() {
+        let block = Block::Header(HeaderBlock::new("header"));
+        let json = serde_json::to_string_pretty(&block).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "header",
+  "text": {
+    "type": "plain_text",
+    "text": "header"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
     #[test]
     fn test_ser_actions() {
         let block = Block::Actions(ActionsBlock::new(vec![Button(ButtonElement::new(
@@ -123,4 +246,160 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_ser_input() {
+        let block: Block = InputBlock::new(
+            "label",
+            PlainTextInput(PlainTextInputElement::new("action_id")),
+        )
+        .into();
+        let json = serde_json::to_string_pretty(&block).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "input",
+  "label": {
+    "type": "plain_text",
+    "text": "label"
+  },
+  "element": {
+    "type": "plain_text_input",
+    "action_id": "action_id"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_file() {
+        let block: Block = FileBlock::new("external_id").block_id("block").into();
+        let json = serde_json::to_string_pretty(&block).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "file",
+  "external_id": "external_id",
+  "source": "remote",
+  "block_id": "block"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_rich_text() {
+        let block: Block = RichTextBlock::new(vec![Quote(RichTextQuote::new(vec![Text(
+            TextElement::new("quote"),
+        )]))])
+        .into();
+        let json = serde_json::to_string_pretty(&block).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "rich_text",
+  "elements": [
+    {
+      "type": "rich_text_quote",
+      "elements": [
+        {
+          "type": "text",
+          "text": "quote"
+        }
+      ]
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_video() {
+        let block: Block = VideoBlock::new("video_url", "thumbnail_url", "alt_text", "title")
+            .title_url("title_url")
+            .into();
+        let json = serde_json::to_string_pretty(&block).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "video",
+  "video_url": "video_url",
+  "thumbnail_url": "thumbnail_url",
+  "alt_text": "alt_text",
+  "title": {
+    "type": "plain_text",
+    "text": "title"
+  },
+  "title_url": "title_url"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_de_blocks() {
+        let json = r#"[
+  {
+    "type": "section",
+    "block_id": "a",
+    "text": { "type": "mrkdwn", "text": "*text*", "verbatim": false },
+    "accessory": {
+      "type": "button",
+      "action_id": "action_id",
+      "text": { "type": "plain_text", "text": "text", "emoji": true }
+    }
+  },
+  { "type": "divider", "block_id": "b" },
+  {
+    "type": "context",
+    "block_id": "c",
+    "elements": [{ "type": "mrkdwn", "text": "context" }]
+  }
+]"#;
+        let blocks = serde_json::from_str::<Vec<Block>>(json).unwrap();
+        let expected: Vec<Block> = vec![
+            SectionBlock::new(Markdown(MarkdownText::new("*text*").verbatim(false)))
+                .block_id("a")
+                .accessory(Button(ButtonElement::new(
+                    PlainText::new("text").emoji(true),
+                    "action_id",
+                )))
+                .into(),
+            DividerBlock::new().block_id("b").into(),
+            ContextBlock::new(vec![TextContext(Markdown("context".into()))])
+                .block_id("c")
+                .into(),
+        ];
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn test_de_unknown_type() {
+        let json = r#"{"type":"call","block_id":"block","call_id":"R123"}"#;
+        let block = serde_json::from_str::<Block>(json).unwrap();
+        let value: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(block, Unknown(value.clone()));
+        assert_eq!(serde_json::to_value(&block).unwrap(), value);
+        assert_eq!(block.validate(), Ok(()));
+
+        let json = r#"{ "block_id": "block" }"#;
+        let error = serde_json::from_str::<Block>(json).unwrap_err();
+        assert!(error.to_string().starts_with("missing field `type`"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let blocks: Vec<Block> = vec![
+            SectionBlock::new(Plain(PlainText::new("text"))).into(),
+            DividerBlock::new().into(),
+            ActionsBlock::new(vec![Button(ButtonElement::new("text", "action_id"))]).into(),
+            ImageBlock::new("image_url", "alt_text").into(),
+            ContextBlock::new(vec![TextContext(Plain("text".into()))]).into(),
+            InputBlock::new(
+                "label",
+                PlainTextInput(PlainTextInputElement::new("action_id")),
+            )
+            .into(),
+            FileBlock::new("external_id").into(),
+            HeaderBlock::new("header").into(),
+            RichTextBlock::new(vec![Quote(RichTextQuote::new(vec![Text(
+                TextElement::new("quote"),
+            )]))])
+            .into(),
+            VideoBlock::new("video_url", "thumbnail_url", "alt_text", "title").into(),
+        ];
+        let json = serde_json::to_string(&blocks).unwrap();
+        let decoded = serde_json::from_str::<Vec<Block>>(&json).unwrap();
+        assert_eq!(decoded, blocks);
+    }
 }
@@ -0,0 +1,930 @@
+use crate::block::rich_text::RichTextElement::{List, Preformatted, Quote, Section};
+use crate::block::rich_text::RichTextInlineElement::{
+    Broadcast, Channel, Date, Emoji, Link, Text, Unknown, User, Usergroup,
+};
+use crate::block::RICH_TEXT_TYPE;
+use crate::de::tagged;
+use crate::validation::{check_optional_length, field, Validate, Violation};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
+
+const SECTION_TYPE: &str = "rich_text_section";
+const LIST_TYPE: &str = "rich_text_list";
+const QUOTE_TYPE: &str = "rich_text_quote";
+const PREFORMATTED_TYPE: &str = "rich_text_preformatted";
+
+const TEXT_TYPE: &str = "text";
+const LINK_TYPE: &str = "link";
+const USER_TYPE: &str = "user";
+const CHANNEL_TYPE: &str = "channel";
+const USERGROUP_TYPE: &str = "usergroup";
+const EMOJI_TYPE: &str = "emoji";
+const BROADCAST_TYPE: &str = "broadcast";
+const DATE_TYPE: &str = "date";
+
+/// Displays formatted, structured representation of text.
+/// Slack sends this block for every message a user types in the composer.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::block::rich_text::RichTextElement::Section;
+/// use block_kit::block::rich_text::RichTextInlineElement::{Text, User};
+/// use block_kit::block::rich_text::{
+///     RichTextBlock, RichTextSection, TextElement, TextStyle, UserElement,
+/// };
+///
+/// let rich_text = RichTextBlock::new(vec![Section(RichTextSection::new(vec![
+///     Text(TextElement::new("Hello ").style(TextStyle::new().bold(true))),
+///     User(UserElement::new("U123456")),
+/// ]))]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RichTextBlock {
+    #[serde(rename = "type")]
+    type_name: String,
+    elements: Vec<RichTextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_id: Option<String>,
+}
+
+impl RichTextBlock {
+    pub fn new(elements: Vec<RichTextElement>) -> Self {
+        RichTextBlock {
+            type_name: RICH_TEXT_TYPE.to_string(),
+            elements,
+            block_id: Option::default(),
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn elements(&self) -> &[RichTextElement] {
+        &self.elements
+    }
+}
+
+/// Deserializing chooses the variant by `type` of the element.
+/// Elements of unknown `type` are kept as `Unknown` and serialized back unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum RichTextElement {
+    Section(RichTextSection),
+    List(RichTextList),
+    Quote(RichTextQuote),
+    Preformatted(RichTextPreformatted),
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for RichTextElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let element = match type_name.as_str() {
+            SECTION_TYPE => from_value(value).map(Section),
+            LIST_TYPE => from_value(value).map(List),
+            QUOTE_TYPE => from_value(value).map(Quote),
+            PREFORMATTED_TYPE => from_value(value).map(Preformatted),
+            _ => Ok(RichTextElement::Unknown(value)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+/// A paragraph of inline elements.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RichTextSection {
+    #[serde(rename = "type")]
+    type_name: String,
+    elements: Vec<RichTextInlineElement>,
+}
+
+impl RichTextSection {
+    pub fn new(elements: Vec<RichTextInlineElement>) -> Self {
+        RichTextSection {
+            type_name: SECTION_TYPE.to_string(),
+            elements,
+        }
+    }
+
+    pub fn elements(&self) -> &[RichTextInlineElement] {
+        &self.elements
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListStyle {
+    Bullet,
+    Ordered,
+}
+
+/// Each `RichTextSection` in `elements` is displayed as a list item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RichTextList {
+    #[serde(rename = "type")]
+    type_name: String,
+    style: ListStyle,
+    elements: Vec<RichTextSection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    indent: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border: Option<u32>,
+}
+
+impl RichTextList {
+    pub fn new(style: ListStyle, elements: Vec<RichTextSection>) -> Self {
+        RichTextList {
+            type_name: LIST_TYPE.to_string(),
+            style,
+            elements,
+            indent: Option::default(),
+            offset: Option::default(),
+            border: Option::default(),
+        }
+    }
+
+    /// Nesting level of the list, starting from 0.
+    pub fn indent(mut self, indent: u32) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Number of items to skip before the first item of an ordered list.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn border(mut self, border: u32) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    pub fn style(&self) -> &ListStyle {
+        &self.style
+    }
+
+    pub fn elements(&self) -> &[RichTextSection] {
+        &self.elements
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RichTextQuote {
+    #[serde(rename = "type")]
+    type_name: String,
+    elements: Vec<RichTextInlineElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border: Option<u32>,
+}
+
+impl RichTextQuote {
+    pub fn new(elements: Vec<RichTextInlineElement>) -> Self {
+        RichTextQuote {
+            type_name: QUOTE_TYPE.to_string(),
+            elements,
+            border: Option::default(),
+        }
+    }
+
+    pub fn border(mut self, border: u32) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    pub fn elements(&self) -> &[RichTextInlineElement] {
+        &self.elements
+    }
+}
+
+/// Code block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RichTextPreformatted {
+    #[serde(rename = "type")]
+    type_name: String,
+    elements: Vec<RichTextInlineElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl RichTextPreformatted {
+    pub fn new(elements: Vec<RichTextInlineElement>) -> Self {
+        RichTextPreformatted {
+            type_name: PREFORMATTED_TYPE.to_string(),
+            elements,
+            border: Option::default(),
+            language: Option::default(),
+        }
+    }
+
+    pub fn border(mut self, border: u32) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Language for syntax highlighting, like `rust`.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn elements(&self) -> &[RichTextInlineElement] {
+        &self.elements
+    }
+}
+
+/// Deserializing chooses the variant by `type` of the element.
+/// Elements of unknown `type`, like `color` or `team`, are kept as `Unknown`
+/// and serialized back unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum RichTextInlineElement {
+    Text(TextElement),
+    Link(LinkElement),
+    User(UserElement),
+    Channel(ChannelElement),
+    Usergroup(UsergroupElement),
+    Emoji(EmojiElement),
+    Broadcast(BroadcastElement),
+    Date(DateElement),
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for RichTextInlineElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let element = match type_name.as_str() {
+            TEXT_TYPE => from_value(value).map(Text),
+            LINK_TYPE => from_value(value).map(Link),
+            USER_TYPE => from_value(value).map(User),
+            CHANNEL_TYPE => from_value(value).map(Channel),
+            USERGROUP_TYPE => from_value(value).map(Usergroup),
+            EMOJI_TYPE => from_value(value).map(Emoji),
+            BROADCAST_TYPE => from_value(value).map(Broadcast),
+            DATE_TYPE => from_value(value).map(Date),
+            _ => Ok(Unknown(value)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+/// Styles applied to inline elements. Unset styles are omitted.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bold: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    italic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strike: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unlink: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    highlight: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_highlight: Option<bool>,
+}
+
+impl TextStyle {
+    pub fn new() -> Self {
+        TextStyle::default()
+    }
+
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = Some(bold);
+        self
+    }
+
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+
+    pub fn strike(mut self, strike: bool) -> Self {
+        self.strike = Some(strike);
+        self
+    }
+
+    pub fn code(mut self, code: bool) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn unlink(mut self, unlink: bool) -> Self {
+        self.unlink = Some(unlink);
+        self
+    }
+
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    pub fn client_highlight(mut self, client_highlight: bool) -> Self {
+        self.client_highlight = Some(client_highlight);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+impl TextElement {
+    pub fn new(text: impl Into<String>) -> Self {
+        TextElement {
+            type_name: TEXT_TYPE.to_string(),
+            text: text.into(),
+            style: Option::default(),
+        }
+    }
+
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+impl LinkElement {
+    pub fn new(url: impl Into<String>) -> Self {
+        LinkElement {
+            type_name: LINK_TYPE.to_string(),
+            url: url.into(),
+            text: Option::default(),
+            style: Option::default(),
+        }
+    }
+
+    /// Displayed instead of `url`.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Mention of a user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+impl UserElement {
+    pub fn new(user_id: impl Into<String>) -> Self {
+        UserElement {
+            type_name: USER_TYPE.to_string(),
+            user_id: user_id.into(),
+            style: Option::default(),
+        }
+    }
+
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+}
+
+/// Mention of a channel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    channel_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+impl ChannelElement {
+    pub fn new(channel_id: impl Into<String>) -> Self {
+        ChannelElement {
+            type_name: CHANNEL_TYPE.to_string(),
+            channel_id: channel_id.into(),
+            style: Option::default(),
+        }
+    }
+
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn channel_id(&self) -> &str {
+        &self.channel_id
+    }
+}
+
+/// Mention of a user group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsergroupElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    usergroup_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+impl UsergroupElement {
+    pub fn new(usergroup_id: impl Into<String>) -> Self {
+        UsergroupElement {
+            type_name: USERGROUP_TYPE.to_string(),
+            usergroup_id: usergroup_id.into(),
+            style: Option::default(),
+        }
+    }
+
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn usergroup_id(&self) -> &str {
+        &self.usergroup_id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmojiElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unicode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skin_tone: Option<u32>,
+}
+
+impl EmojiElement {
+    /// Emoji name without colons, like `tada`.
+    pub fn new(name: impl Into<String>) -> Self {
+        EmojiElement {
+            type_name: EMOJI_TYPE.to_string(),
+            name: name.into(),
+            unicode: Option::default(),
+            skin_tone: Option::default(),
+        }
+    }
+
+    /// Code point of the emoji, like `1f389`.
+    pub fn unicode(mut self, unicode: impl Into<String>) -> Self {
+        self.unicode = Some(unicode.into());
+        self
+    }
+
+    /// Skin tone modifier from 2 to 6, like `2` for `:wave::skin-tone-2:`.
+    pub fn skin_tone(mut self, skin_tone: u32) -> Self {
+        self.skin_tone = Some(skin_tone);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BroadcastRange {
+    Here,
+    Channel,
+    Everyone,
+}
+
+/// `@here`, `@channel` or `@everyone`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BroadcastElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    range: BroadcastRange,
+}
+
+impl BroadcastElement {
+    pub fn new(range: BroadcastRange) -> Self {
+        BroadcastElement {
+            type_name: BROADCAST_TYPE.to_string(),
+            range,
+        }
+    }
+
+    pub fn range(&self) -> &BroadcastRange {
+        &self.range
+    }
+}
+
+/// Date displayed in the reader's timezone.
+///
+/// `format` uses the tokens described in
+/// https://api.slack.com/reference/surfaces/formatting#date-formatting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    timestamp: i64,
+    format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fallback: Option<String>,
+}
+
+impl DateElement {
+    pub fn new(timestamp: i64, format: impl Into<String>) -> Self {
+        DateElement {
+            type_name: DATE_TYPE.to_string(),
+            timestamp,
+            format: format.into(),
+            url: Option::default(),
+            fallback: Option::default(),
+        }
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Displayed when the client can't render the date.
+    pub fn fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+impl Validate for RichTextBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_new() {
+        let rich_text = RichTextBlock::new(vec![Section(RichTextSection::new(vec![Text(
+            TextElement::new("text"),
+        )]))]);
+        let json = serde_json::to_string_pretty(&rich_text).unwrap();
+        let expected = r#"{
+  "type": "rich_text",
+  "elements": [
+    {
+      "type": "rich_text_section",
+      "elements": [
+        {
+          "type": "text",
+          "text": "text"
+        }
+      ]
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_list() {
+        let list = RichTextList::new(
+            ListStyle::Ordered,
+            vec![RichTextSection::new(vec![Text(TextElement::new("item"))])],
+        )
+        .indent(1)
+        .offset(2)
+        .border(0);
+        let rich_text = RichTextBlock::new(vec![List(list)]).block_id("block");
+        let json = serde_json::to_string_pretty(&rich_text).unwrap();
+        let expected = r#"{
+  "type": "rich_text",
+  "elements": [
+    {
+      "type": "rich_text_list",
+      "style": "ordered",
+      "elements": [
+        {
+          "type": "rich_text_section",
+          "elements": [
+            {
+              "type": "text",
+              "text": "item"
+            }
+          ]
+        }
+      ],
+      "indent": 1,
+      "offset": 2,
+      "border": 0
+    }
+  ],
+  "block_id": "block"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_inline_elements() {
+        let section = RichTextSection::new(vec![
+            Text(TextElement::new("text").style(TextStyle::new().bold(true).code(true))),
+            Link(LinkElement::new("https://example.com").text("link")),
+            User(UserElement::new("U1")),
+            Channel(ChannelElement::new("C1")),
+            Usergroup(UsergroupElement::new("S1")),
+            Emoji(EmojiElement::new("tada").unicode("1f389")),
+            Broadcast(BroadcastElement::new(BroadcastRange::Here)),
+            Date(DateElement::new(1_600_000_000, "{date_short}").fallback("fallback")),
+        ]);
+        let json = serde_json::to_string_pretty(&section).unwrap();
+        let expected = r#"{
+  "type": "rich_text_section",
+  "elements": [
+    {
+      "type": "text",
+      "text": "text",
+      "style": {
+        "bold": true,
+        "code": true
+      }
+    },
+    {
+      "type": "link",
+      "url": "https://example.com",
+      "text": "link"
+    },
+    {
+      "type": "user",
+      "user_id": "U1"
+    },
+    {
+      "type": "channel",
+      "channel_id": "C1"
+    },
+    {
+      "type": "usergroup",
+      "usergroup_id": "S1"
+    },
+    {
+      "type": "emoji",
+      "name": "tada",
+      "unicode": "1f389"
+    },
+    {
+      "type": "broadcast",
+      "range": "here"
+    },
+    {
+      "type": "date",
+      "timestamp": 1600000000,
+      "format": "{date_short}",
+      "fallback": "fallback"
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_de_message_block() {
+        let json = r#"{
+  "type": "rich_text",
+  "block_id": "Xy1",
+  "elements": [
+    {
+      "type": "rich_text_section",
+      "elements": [
+        { "type": "text", "text": "Hi " },
+        { "type": "user", "user_id": "U1" },
+        { "type": "text", "text": " see", "style": { "italic": true } }
+      ]
+    },
+    {
+      "type": "rich_text_list",
+      "style": "bullet",
+      "indent": 0,
+      "elements": [
+        {
+          "type": "rich_text_section",
+          "elements": [{ "type": "emoji", "name": "tada" }]
+        }
+      ]
+    },
+    {
+      "type": "rich_text_quote",
+      "elements": [{ "type": "broadcast", "range": "channel" }]
+    },
+    {
+      "type": "rich_text_preformatted",
+      "border": 0,
+      "elements": [{ "type": "text", "text": "let a = 1;" }]
+    }
+  ]
+}"#;
+        let rich_text = serde_json::from_str::<RichTextBlock>(json).unwrap();
+        assert_eq!(rich_text.block_id, Some("Xy1".to_string()));
+        assert_eq!(rich_text.elements.len(), 4);
+
+        match &rich_text.elements[0] {
+            Section(section) => {
+                assert_eq!(section.elements[1], User(UserElement::new("U1")));
+                assert_eq!(
+                    section.elements[2],
+                    Text(TextElement::new(" see").style(TextStyle::new().italic(true)))
+                );
+            }
+            _ => panic!("expected rich_text_section"),
+        }
+        match &rich_text.elements[1] {
+            List(list) => {
+                assert_eq!(list.style, ListStyle::Bullet);
+                assert_eq!(list.indent, Some(0));
+                assert_eq!(
+                    list.elements[0].elements[0],
+                    Emoji(EmojiElement::new("tada"))
+                );
+            }
+            _ => panic!("expected rich_text_list"),
+        }
+        match &rich_text.elements[2] {
+            Quote(quote) => assert_eq!(
+                quote.elements[0],
+                Broadcast(BroadcastElement::new(BroadcastRange::Channel))
+            ),
+            _ => panic!("expected rich_text_quote"),
+        }
+        match &rich_text.elements[3] {
+            Preformatted(preformatted) => assert_eq!(preformatted.border, Some(0)),
+            _ => panic!("expected rich_text_preformatted"),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_message_fields() {
+        let json = r#"{
+  "type": "rich_text",
+  "elements": [
+    {
+      "type": "rich_text_section",
+      "elements": [
+        { "type": "emoji", "name": "wave", "unicode": "1f44b-1f3fb", "skin_tone": 2 },
+        {
+          "type": "link",
+          "url": "https://example.com",
+          "style": { "unlink": true, "highlight": true, "client_highlight": false }
+        }
+      ]
+    },
+    {
+      "type": "rich_text_preformatted",
+      "language": "rust",
+      "elements": [{ "type": "text", "text": "let a = 1;" }]
+    }
+  ]
+}"#;
+        let rich_text = serde_json::from_str::<RichTextBlock>(json).unwrap();
+        match &rich_text.elements()[0] {
+            Section(section) => {
+                assert_eq!(
+                    section.elements()[0],
+                    Emoji(
+                        EmojiElement::new("wave")
+                            .unicode("1f44b-1f3fb")
+                            .skin_tone(2)
+                    )
+                );
+                assert_eq!(
+                    section.elements()[1],
+                    Link(
+                        LinkElement::new("https://example.com").style(
+                            TextStyle::new()
+                                .unlink(true)
+                                .highlight(true)
+                                .client_highlight(false)
+                        )
+                    )
+                );
+            }
+            _ => panic!("expected rich_text_section"),
+        }
+        assert_eq!(
+            serde_json::to_value(&rich_text).unwrap(),
+            serde_json::from_str::<Value>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_de_unknown_elements() {
+        let json = r##"{
+  "type": "rich_text",
+  "elements": [
+    {
+      "type": "rich_text_section",
+      "elements": [
+        { "type": "color", "value": "#F405B3" },
+        { "type": "team", "team_id": "T1" },
+        { "type": "text", "text": " ok" }
+      ]
+    },
+    { "type": "rich_text_unknown", "elements": [] }
+  ]
+}"##;
+        let rich_text = serde_json::from_str::<RichTextBlock>(json).unwrap();
+        match &rich_text.elements()[0] {
+            Section(section) => {
+                assert_eq!(
+                    section.elements()[0],
+                    Unknown(serde_json::json!({ "type": "color", "value": "#F405B3" }))
+                );
+                assert_eq!(
+                    section.elements()[1],
+                    Unknown(serde_json::json!({ "type": "team", "team_id": "T1" }))
+                );
+                assert_eq!(section.elements()[2], Text(TextElement::new(" ok")));
+            }
+            element => panic!("unexpected element: {:?}", element),
+        }
+        match &rich_text.elements()[1] {
+            RichTextElement::Unknown(value) => assert_eq!(value["type"], "rich_text_unknown"),
+            element => panic!("unexpected element: {:?}", element),
+        }
+
+        let echoed = serde_json::to_value(&rich_text).unwrap();
+        assert_eq!(echoed, serde_json::from_str::<Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let rich_text = RichTextBlock::new(vec![
+            Section(RichTextSection::new(vec![
+                Text(TextElement::new("text").style(TextStyle::new().strike(true))),
+                Date(DateElement::new(0, "{date}").url("https://example.com")),
+            ])),
+            Quote(RichTextQuote::new(vec![Link(LinkElement::new("url"))]).border(1)),
+            Preformatted(RichTextPreformatted::new(vec![Text(TextElement::new(
+                "code",
+            ))])),
+            List(RichTextList::new(
+                ListStyle::Bullet,
+                vec![RichTextSection::new(vec![Channel(ChannelElement::new(
+                    "C1",
+                ))])],
+            )),
+        ])
+        .block_id("block");
+        let json = serde_json::to_string(&rich_text).unwrap();
+        let decoded = serde_json::from_str::<RichTextBlock>(&json).unwrap();
+        assert_eq!(decoded, rich_text);
+    }
+}
@@ -1,20 +1,24 @@
 use crate::block::SECTION_TYPE;
 use crate::block_element::BlockElement;
 use crate::composition::text::Text;
-use serde::Serialize;
+use crate::validation::{
+    check_count, check_optional_length, check_text, field, index, Validate, Violation,
+};
+use serde::{Deserialize, Serialize};
 
 /// A section is one of the most flexible blocks available.
 /// - simple text
 /// - multiple text fields
 /// - `Element` accessory
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SectionBlock {
     #[serde(rename = "type")]
-    type_name: &'static str,
-    text: Text,
+    type_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Text>,
     #[serde(skip_serializing_if = "Option::is_none")]
     block_id: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     fields: Vec<Text>,
     #[serde(skip_serializing_if = "Option::is_none")]
     accessory: Option<BlockElement>,
@@ -23,14 +27,25 @@ pub struct SectionBlock {
 impl SectionBlock {
     pub fn new(text: Text) -> Self {
         SectionBlock {
-            type_name: SECTION_TYPE,
-            text,
+            type_name: SECTION_TYPE.to_string(),
+            text: Some(text),
             block_id: Option::default(),
             fields: Vec::default(),
             accessory: Option::default(),
         }
     }
 
+    /// A section without `text`, which shows only `fields`.
+    pub fn from_fields(fields: Vec<Text>) -> Self {
+        SectionBlock {
+            type_name: SECTION_TYPE.to_string(),
+            text: Option::default(),
+            block_id: Option::default(),
+            fields,
+            accessory: Option::default(),
+        }
+    }
+
     pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
         self.block_id = Some(block_id.into());
         self
@@ -47,12 +62,28 @@ impl SectionBlock {
     }
 }
 
+impl Validate for SectionBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        if let Some(text) = &self.text {
+            check_text(&field(path, "text"), text, 3000, violations);
+        }
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+        check_count(field(path, "fields"), &self.fields, 10, violations);
+        for (i, text) in self.fields.iter().enumerate() {
+            check_text(&index(&field(path, "fields"), i), text, 2000, violations);
+        }
+        self.accessory
+            .collect_violations(&field(path, "accessory"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::block_element::button::ButtonElement;
     use crate::block_element::BlockElement::Button;
     use crate::composition::text::PlainText;
+    use crate::composition::text::Text::Plain;
 
     #[test]
     fn test_ser_new() {
@@ -103,6 +134,26 @@ mod test {
         assert_eq!(json, expected);
     }
 
+    #[test]
+    fn test_ser_from_fields() {
+        let section = SectionBlock::from_fields(vec![Text::default()]);
+        let json = serde_json::to_string_pretty(&section).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "section",
+  "fields": [
+    {
+      "type": "plain_text",
+      "text": ""
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+        assert_eq!(
+            serde_json::from_str::<SectionBlock>(&json).unwrap(),
+            section
+        );
+    }
+
     #[test]
     fn test_ser_accessory() {
         let button = ButtonElement::new(PlainText::new("text"), "action_id");
@@ -161,4 +212,35 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let section = SectionBlock::new(Text::default())
+            .block_id("block")
+            .fields(vec![Text::default()])
+            .accessory(Button(ButtonElement::new(
+                PlainText::new("text"),
+                "action_id",
+            )));
+        let json = serde_json::to_string(&section).unwrap();
+        let decoded = serde_json::from_str::<SectionBlock>(&json).unwrap();
+        assert_eq!(decoded, section);
+    }
+
+    #[test]
+    fn test_validate() {
+        let section = SectionBlock::new(Text::default()).fields(vec![Text::default(); 10]);
+        assert_eq!(section.validate(), Ok(()));
+
+        let section = SectionBlock::new(Plain(PlainText::new("a".repeat(3001))))
+            .fields(vec![Text::default(); 11])
+            .accessory(Button(ButtonElement::new("a".repeat(76), "action_id")));
+        let paths: Vec<String> = section
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|violation| violation.path)
+            .collect();
+        assert_eq!(paths, vec!["text.text", "fields", "accessory.text.text"]);
+    }
 }
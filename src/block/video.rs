@@ -0,0 +1,183 @@
+use crate::block::VIDEO_TYPE;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::validation::{check_optional_length, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// Displays an embedded video player.
+///
+/// WARNING
+/// `video_url` must be on a domain registered in the app's unfurl domains.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoBlock {
+    #[serde(rename = "type")]
+    type_name: String,
+    video_url: String,
+    thumbnail_url: String,
+    alt_text: String,
+    title: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_id: Option<String>,
+}
+
+impl VideoBlock {
+    pub fn new(
+        video_url: impl Into<String>,
+        thumbnail_url: impl Into<String>,
+        alt_text: impl Into<String>,
+        title: impl Into<PlainText>,
+    ) -> Self {
+        VideoBlock {
+            type_name: VIDEO_TYPE.to_string(),
+            video_url: video_url.into(),
+            thumbnail_url: thumbnail_url.into(),
+            alt_text: alt_text.into(),
+            title: Plain(title.into()),
+            title_url: Option::default(),
+            description: Option::default(),
+            provider_name: Option::default(),
+            provider_icon_url: Option::default(),
+            author_name: Option::default(),
+            block_id: Option::default(),
+        }
+    }
+
+    /// Hyperlink for the title text.
+    pub fn title_url(mut self, title_url: impl Into<String>) -> Self {
+        self.title_url = Some(title_url.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<PlainText>) -> Self {
+        self.description = Some(Plain(description.into()));
+        self
+    }
+
+    /// The originating application or domain of the video, like `YouTube`.
+    pub fn provider_name(mut self, provider_name: impl Into<String>) -> Self {
+        self.provider_name = Some(provider_name.into());
+        self
+    }
+
+    pub fn provider_icon_url(mut self, provider_icon_url: impl Into<String>) -> Self {
+        self.provider_icon_url = Some(provider_icon_url.into());
+        self
+    }
+
+    pub fn author_name(mut self, author_name: impl Into<String>) -> Self {
+        self.author_name = Some(author_name.into());
+        self
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+impl Validate for VideoBlock {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "title"), &self.title, 200, violations);
+        check_optional_length(field(path, "block_id"), &self.block_id, 255, violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_new() {
+        let video = VideoBlock::new("video_url", "thumbnail_url", "alt", PlainText::new("title"));
+        let json = serde_json::to_string_pretty(&video).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "video",
+  "video_url": "video_url",
+  "thumbnail_url": "thumbnail_url",
+  "alt_text": "alt",
+  "title": {
+    "type": "plain_text",
+    "text": "title"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_description() {
+        let video = VideoBlock::new("video_url", "thumbnail_url", "alt", "title")
+            .description(PlainText::new("description"));
+        let json = serde_json::to_string_pretty(&video).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "video",
+  "video_url": "video_url",
+  "thumbnail_url": "thumbnail_url",
+  "alt_text": "alt",
+  "title": {
+    "type": "plain_text",
+    "text": "title"
+  },
+  "description": {
+    "type": "plain_text",
+    "text": "description"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_all() {
+        let video = VideoBlock::new("video_url", "thumbnail_url", "alt", "title")
+            .title_url("title_url")
+            .description("description")
+            .provider_name("provider")
+            .provider_icon_url("icon_url")
+            .author_name("author")
+            .block_id("id");
+        let json = serde_json::to_string_pretty(&video).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "video",
+  "video_url": "video_url",
+  "thumbnail_url": "thumbnail_url",
+  "alt_text": "alt",
+  "title": {
+    "type": "plain_text",
+    "text": "title"
+  },
+  "title_url": "title_url",
+  "description": {
+    "type": "plain_text",
+    "text": "description"
+  },
+  "provider_name": "provider",
+  "provider_icon_url": "icon_url",
+  "author_name": "author",
+  "block_id": "id"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let video = VideoBlock::new("video_url", "thumbnail_url", "alt", "title")
+            .title_url("title_url")
+            .description("description")
+            .provider_name("provider")
+            .provider_icon_url("icon_url")
+            .author_name("author")
+            .block_id("id");
+        let json = serde_json::to_string(&video).unwrap();
+        let decoded = serde_json::from_str::<VideoBlock>(&json).unwrap();
+        assert_eq!(decoded, video);
+    }
+}
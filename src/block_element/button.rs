@@ -2,12 +2,15 @@ use crate::block_element::BUTTON_TYPE;
 use crate::composition::confirmation_dialog::ConfirmationDialog;
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{
+    check_length, check_optional_length, check_text, field, Validate, Violation,
+};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ButtonElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     text: Text,
     action_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,7 +23,7 @@ pub struct ButtonElement {
     confirm: Option<ConfirmationDialog>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Style {
     Primary,
@@ -30,7 +33,7 @@ pub enum Style {
 impl ButtonElement {
     pub fn new(text: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
         ButtonElement {
-            type_name: BUTTON_TYPE,
+            type_name: BUTTON_TYPE.to_string(),
             text: Plain(text.into()),
             action_id: action_id.into(),
             url: Option::default(),
@@ -61,6 +64,17 @@ impl ButtonElement {
     }
 }
 
+impl Validate for ButtonElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "text"), &self.text, 75, violations);
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        check_optional_length(field(path, "url"), &self.url, 3000, violations);
+        check_optional_length(field(path, "value"), &self.value, 2000, violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
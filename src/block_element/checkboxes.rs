@@ -0,0 +1,124 @@
+use crate::block_element::CHECKBOXES_TYPE;
+use crate::composition::confirmation_dialog::ConfirmationDialog;
+use crate::composition::option::OptionObject;
+use crate::validation::{check_count, check_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// A set of checkboxes.
+///
+/// `options`: Maximum number of options is 10.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckboxesElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    action_id: String,
+    options: Vec<OptionObject>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    initial_options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl CheckboxesElement {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        CheckboxesElement {
+            type_name: CHECKBOXES_TYPE.to_string(),
+            action_id: action_id.into(),
+            options,
+            initial_options: Vec::default(),
+            confirm: Option::default(),
+        }
+    }
+
+    /// Must exactly match one or more of the `options`.
+    pub fn initial_options(mut self, initial_options: Vec<OptionObject>) -> Self {
+        self.initial_options = initial_options;
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+impl Validate for CheckboxesElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        check_count(field(path, "options"), &self.options, 10, violations);
+        self.options
+            .collect_violations(&field(path, "options"), violations);
+        self.initial_options
+            .collect_violations(&field(path, "initial_options"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::ViolationKind::TooMany;
+
+    #[test]
+    fn test_ser_all() {
+        let option1 = OptionObject::new("text1", "value1").description("description");
+        let option2 = OptionObject::new("text2", "value2");
+        let element = CheckboxesElement::new("action_id", vec![option1, option2.clone()])
+            .initial_options(vec![option2]);
+        let json = serde_json::to_string_pretty(&element).unwrap();
+
+        let expected = r#"{
+  "type": "checkboxes",
+  "action_id": "action_id",
+  "options": [
+    {
+      "text": {
+        "type": "plain_text",
+        "text": "text1"
+      },
+      "value": "value1",
+      "description": {
+        "type": "plain_text",
+        "text": "description"
+      }
+    },
+    {
+      "text": {
+        "type": "plain_text",
+        "text": "text2"
+      },
+      "value": "value2"
+    }
+  ],
+  "initial_options": [
+    {
+      "text": {
+        "type": "plain_text",
+        "text": "text2"
+      },
+      "value": "value2"
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let options = (0..11)
+            .map(|i| OptionObject::new("text", format!("value{}", i)))
+            .collect();
+        let element = CheckboxesElement::new("action_id", options);
+        assert_eq!(
+            element.validate(),
+            Err(vec![Violation {
+                path: "options".to_string(),
+                kind: TooMany {
+                    max: 10,
+                    actual: 11
+                },
+            }])
+        );
+    }
+}
@@ -1,27 +1,29 @@
+use crate::block_element::date_time::Date;
 use crate::block_element::DATE_PICKER_TYPE;
 use crate::composition::confirmation_dialog::ConfirmationDialog;
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{check_length, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DatePickerElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     action_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     placeholder: Option<Text>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_date: Option<String>,
+    initial_date: Option<Date>,
     #[serde(skip_serializing_if = "Option::is_none")]
     confirm: Option<ConfirmationDialog>,
 }
 
 impl DatePickerElement {
-    pub fn new(action_id: &str) -> Self {
+    pub fn new(action_id: impl Into<String>) -> Self {
         DatePickerElement {
-            type_name: DATE_PICKER_TYPE,
-            action_id: action_id.to_string(),
+            type_name: DATE_PICKER_TYPE.to_string(),
+            action_id: action_id.into(),
             placeholder: Option::default(),
             initial_date: Option::default(),
             confirm: Option::default(),
@@ -33,8 +35,8 @@ impl DatePickerElement {
         self
     }
 
-    pub fn initial_date(mut self, initial_date: impl Into<String>) -> Self {
-        self.initial_date = Some(initial_date.into());
+    pub fn initial_date(mut self, initial_date: Date) -> Self {
+        self.initial_date = Some(initial_date);
         self
     }
 
@@ -44,6 +46,17 @@ impl DatePickerElement {
     }
 }
 
+impl Validate for DatePickerElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(placeholder) = &self.placeholder {
+            check_text(&field(path, "placeholder"), placeholder, 150, violations);
+        }
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -92,7 +105,8 @@ mod test {
 
     #[test]
     fn test_ser_initial_date() {
-        let date_picker = DatePickerElement::new("action_id").initial_date("2013-12-11");
+        let date_picker =
+            DatePickerElement::new("action_id").initial_date(Date::from_ymd(2013, 12, 11).unwrap());
         let json = serde_json::to_string_pretty(&date_picker).unwrap();
 
         let expected = r#"{
@@ -149,7 +163,7 @@ mod test {
         );
         let date_picker = DatePickerElement::new("action_id")
             .placeholder(PlainText::new("placeholder"))
-            .initial_date("2013-12-11")
+            .initial_date(Date::from_ymd(2013, 12, 11).unwrap())
             .confirm(confirm);
         let json = serde_json::to_string_pretty(&date_picker).unwrap();
 
@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// A date in the format `YYYY-MM-DD`, used by `DatePickerElement`.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::block_element::date_time::Date;
+///
+/// let date: Date = "2020-02-29".parse().unwrap();
+/// assert_eq!(date, Date::from_ymd(2020, 2, 29).unwrap());
+///
+/// assert!("2021-02-29".parse::<Date>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Result<Self, FormatError> {
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => 0,
+        };
+        if year > 9999 || day == 0 || day > days {
+            return Err(FormatError::new(
+                format!("{:04}-{:02}-{:02}", year, month, day),
+                DATE_FORMAT,
+            ));
+        }
+        Ok(Date { year, month, day })
+    }
+}
+
+/// A time in the 24-hour format `HH:mm`, used by `TimePickerElement`.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::block_element::date_time::Time;
+///
+/// let time: Time = "09:30".parse().unwrap();
+/// assert_eq!(time, Time::from_hm(9, 30).unwrap());
+///
+/// assert!("9:30".parse::<Time>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    pub fn from_hm(hour: u8, minute: u8) -> Result<Self, FormatError> {
+        if hour > 23 || minute > 59 {
+            return Err(FormatError::new(
+                format!("{:02}:{:02}", hour, minute),
+                TIME_FORMAT,
+            ));
+        }
+        Ok(Time { hour, minute })
+    }
+}
+
+const DATE_FORMAT: &str = "YYYY-MM-DD";
+const TIME_FORMAT: &str = "HH:mm";
+
+/// Returned when a value does not match the format which Slack expects.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatError {
+    pub value: String,
+    pub expected: &'static str,
+}
+
+impl FormatError {
+    fn new(value: impl Into<String>, expected: &'static str) -> Self {
+        FormatError {
+            value: value.into(),
+            expected,
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` does not match the format {}",
+            self.value, self.expected
+        )
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+/// Parses fixed width digits, like `MM` in `YYYY-MM-DD`.
+fn digits<T: FromStr>(part: &str, width: usize) -> Option<T> {
+    if part.len() == width && part.bytes().all(|b| b.is_ascii_digit()) {
+        part.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl FromStr for Date {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split('-').collect::<Vec<_>>();
+        match parts.as_slice() {
+            [year, month, day] => match (digits(year, 4), digits(month, 2), digits(day, 2)) {
+                (Some(year), Some(month), Some(day)) => Date::from_ymd(year, month, day),
+                _ => Err(FormatError::new(s, DATE_FORMAT)),
+            },
+            _ => Err(FormatError::new(s, DATE_FORMAT)),
+        }
+    }
+}
+
+impl FromStr for Time {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(':').collect::<Vec<_>>();
+        match parts.as_slice() {
+            [hour, minute] => match (digits(hour, 2), digits(minute, 2)) {
+                (Some(hour), Some(minute)) => Time::from_hm(hour, minute),
+                _ => Err(FormatError::new(s, TIME_FORMAT)),
+            },
+            _ => Err(FormatError::new(s, TIME_FORMAT)),
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = FormatError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Time {
+    type Error = FormatError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Date> for String {
+    fn from(date: Date) -> Self {
+        date.to_string()
+    }
+}
+
+impl From<Time> for String {
+    fn from(time: Time) -> Self {
+        time.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!("2013-12-11".parse(), Date::from_ymd(2013, 12, 11));
+        assert_eq!(
+            Date::from_ymd(2000, 2, 29).unwrap().to_string(),
+            "2000-02-29"
+        );
+        assert!(Date::from_ymd(1900, 2, 29).is_err());
+        assert!(Date::from_ymd(2020, 13, 1).is_err());
+        assert!(Date::from_ymd(2020, 4, 31).is_err());
+
+        for invalid in &[
+            "2013-12-1",
+            "2013/12/11",
+            "13-12-11",
+            "2013-12-11T",
+            "+013-12-11",
+        ] {
+            assert_eq!(
+                invalid.parse::<Date>(),
+                Err(FormatError::new(*invalid, DATE_FORMAT))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!("23:59".parse(), Time::from_hm(23, 59));
+        assert_eq!(Time::from_hm(0, 5).unwrap().to_string(), "00:05");
+        assert!(Time::from_hm(24, 0).is_err());
+        assert!(Time::from_hm(12, 60).is_err());
+
+        for invalid in &["1:00", "01:00:00", "01-00", "ab:cd"] {
+            assert_eq!(
+                invalid.parse::<Time>(),
+                Err(FormatError::new(*invalid, TIME_FORMAT))
+            );
+        }
+    }
+
+    #[test]
+    fn test_de() {
+        let date = serde_json::from_str::<Date>(r#""2020-01-01""#).unwrap();
+        assert_eq!(serde_json::to_string(&date).unwrap(), r#""2020-01-01""#);
+
+        let error = serde_json::from_str::<Time>(r#""25:00""#).unwrap_err();
+        assert_eq!(error.to_string(), "`25:00` does not match the format HH:mm");
+    }
+}
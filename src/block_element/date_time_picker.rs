@@ -0,0 +1,67 @@
+use crate::block_element::DATE_TIME_PICKER_TYPE;
+use crate::composition::confirmation_dialog::ConfirmationDialog;
+use crate::validation::{check_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// Picks a date and a time at once.
+///
+/// `initial_date_time`: Unix timestamp in seconds, which is displayed
+/// in the timezone of the user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateTimePickerElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_date_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl DateTimePickerElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        DateTimePickerElement {
+            type_name: DATE_TIME_PICKER_TYPE.to_string(),
+            action_id: action_id.into(),
+            initial_date_time: Option::default(),
+            confirm: Option::default(),
+        }
+    }
+
+    pub fn initial_date_time(mut self, initial_date_time: i64) -> Self {
+        self.initial_date_time = Some(initial_date_time);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+impl Validate for DateTimePickerElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_all() {
+        let date_time_picker =
+            DateTimePickerElement::new("action_id").initial_date_time(1628633820);
+        let json = serde_json::to_string_pretty(&date_time_picker).unwrap();
+
+        let expected = r#"{
+  "type": "datetimepicker",
+  "action_id": "action_id",
+  "initial_date_time": 1628633820
+}"#;
+        assert_eq!(json, expected);
+    }
+}
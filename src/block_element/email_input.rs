@@ -0,0 +1,122 @@
+use crate::block_element::EMAIL_TEXT_INPUT_TYPE;
+use crate::composition::dispatch_action_config::DispatchActionConfig;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::validation::{check_length, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// A plain-text input which only accepts an email address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmailInputElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<Text>,
+}
+
+impl EmailInputElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        EmailInputElement {
+            type_name: EMAIL_TEXT_INPUT_TYPE.to_string(),
+            action_id: action_id.into(),
+            initial_value: Option::default(),
+            dispatch_action_config: Option::default(),
+            focus_on_load: Option::default(),
+            placeholder: Option::default(),
+        }
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn dispatch_action_config(mut self, dispatch_action_config: DispatchActionConfig) -> Self {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+
+    /// Only one element in a view can have `focus_on_load` set to true.
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<PlainText>) -> Self {
+        self.placeholder = Some(Plain(placeholder.into()));
+        self
+    }
+}
+
+impl Validate for EmailInputElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(placeholder) = &self.placeholder {
+            check_text(&field(path, "placeholder"), placeholder, 150, violations);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::composition::dispatch_action_config::TriggerAction;
+
+    #[test]
+    fn test_ser_new() {
+        let input = EmailInputElement::new("action_id");
+        let json = serde_json::to_string_pretty(&input).unwrap();
+
+        let expected = r#"{
+  "type": "email_text_input",
+  "action_id": "action_id"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_all() {
+        let input = EmailInputElement::new("action_id")
+            .initial_value("user@example.com")
+            .dispatch_action_config(DispatchActionConfig::new(vec![
+                TriggerAction::OnEnterPressed,
+            ]))
+            .focus_on_load(true)
+            .placeholder("placeholder");
+        let json = serde_json::to_string_pretty(&input).unwrap();
+
+        let expected = r#"{
+  "type": "email_text_input",
+  "action_id": "action_id",
+  "initial_value": "user@example.com",
+  "dispatch_action_config": {
+    "trigger_actions_on": [
+      "on_enter_pressed"
+    ]
+  },
+  "focus_on_load": true,
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = EmailInputElement::new("action_id")
+            .initial_value("user@example.com")
+            .placeholder("placeholder");
+        let json = serde_json::to_string(&input).unwrap();
+        let decoded = serde_json::from_str::<EmailInputElement>(&json).unwrap();
+        assert_eq!(decoded, input);
+    }
+}
@@ -1,10 +1,10 @@
 use crate::block_element::IMAGE_TYPE;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     image_url: String,
     alt_text: String,
 }
@@ -12,7 +12,7 @@ pub struct ImageElement {
 impl ImageElement {
     pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
         ImageElement {
-            type_name: IMAGE_TYPE,
+            type_name: IMAGE_TYPE.to_string(),
             image_url: image_url.into(),
             alt_text: alt_text.into(),
         }
@@ -1,49 +1,180 @@
 use crate::block_element::button::ButtonElement;
+use crate::block_element::checkboxes::CheckboxesElement;
 use crate::block_element::date_picker::DatePickerElement;
+use crate::block_element::date_time_picker::DateTimePickerElement;
+use crate::block_element::email_input::EmailInputElement;
 use crate::block_element::image::ImageElement;
-use crate::block_element::multi_select_menu::MultiStaticSelectMenuElement;
+use crate::block_element::multi_select_menu::{
+    MultiChannelsSelectMenuElement, MultiConversationsSelectMenuElement,
+    MultiStaticSelectMenuElement, MultiUsersSelectMenuElement,
+};
+use crate::block_element::number_input::NumberInputElement;
 use crate::block_element::overflow_menu::OverflowMenuElement;
 use crate::block_element::plain_text_input::PlainTextInputElement;
-use crate::block_element::select_menu::StaticSelectMenuElement;
-use serde::Serialize;
+use crate::block_element::radio_buttons::RadioButtonsElement;
+use crate::block_element::select_menu::{
+    ChannelsSelectMenuElement, ConversationsSelectMenuElement, ExternalSelectMenuElement,
+    StaticSelectMenuElement, UsersSelectMenuElement,
+};
+use crate::block_element::time_picker::TimePickerElement;
+use crate::block_element::url_input::UrlInputElement;
+use crate::block_element::BlockElement::{
+    Button, ChannelsSelectMenu, Checkboxes, ConversationsSelectMenu, DatePicker, DateTimePicker,
+    EmailInput, ExternalSelectMenu, Image, MultiChannelsSelectMenu, MultiConversationsSelectMenu,
+    MultiStaticSelectMenu, MultiUsersSelectMenu, NumberInput, OverflowMenu, PlainTextInput,
+    RadioButtons, StaticSelectMenu, TimePicker, Unknown, UrlInput, UsersSelectMenu,
+};
+use crate::de::tagged;
+use crate::validation::{Validate, Violation};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
 
 pub mod button;
+pub mod checkboxes;
 pub mod date_picker;
+pub mod date_time;
+pub mod date_time_picker;
+pub mod email_input;
 pub mod image;
 pub mod multi_select_menu;
+pub mod number_input;
 pub mod overflow_menu;
 pub mod plain_text_input;
+pub mod radio_buttons;
 pub mod select_menu;
+pub mod time_picker;
+pub mod url_input;
 
-pub(self) const BUTTON_TYPE: &str = "button";
-pub(self) const DATE_PICKER_TYPE: &str = "datepicker";
-pub(self) const IMAGE_TYPE: &str = "image";
-pub(self) const OVERFLOW_MENU_TYPE: &str = "overflow";
-pub(self) const PLAIN_TEXT_INPUT_TYPE: &str = "plain_text_input";
-pub(self) const STATIC_SELECT_MENU_TYPE: &str = "static_select";
-pub(self) const MULTI_STATIC_SELECT_MENU_TYPE: &str = "multi_static_select";
+pub(crate) const BUTTON_TYPE: &str = "button";
+pub(crate) const CHECKBOXES_TYPE: &str = "checkboxes";
+pub(crate) const DATE_PICKER_TYPE: &str = "datepicker";
+pub(crate) const DATE_TIME_PICKER_TYPE: &str = "datetimepicker";
+pub(crate) const IMAGE_TYPE: &str = "image";
+pub(crate) const OVERFLOW_MENU_TYPE: &str = "overflow";
+pub(crate) const PLAIN_TEXT_INPUT_TYPE: &str = "plain_text_input";
+pub(crate) const EMAIL_TEXT_INPUT_TYPE: &str = "email_text_input";
+pub(crate) const URL_TEXT_INPUT_TYPE: &str = "url_text_input";
+pub(crate) const NUMBER_INPUT_TYPE: &str = "number_input";
+pub(crate) const RADIO_BUTTONS_TYPE: &str = "radio_buttons";
+pub(crate) const STATIC_SELECT_MENU_TYPE: &str = "static_select";
+pub(crate) const TIME_PICKER_TYPE: &str = "timepicker";
+pub(crate) const EXTERNAL_SELECT_MENU_TYPE: &str = "external_select";
+pub(crate) const MULTI_STATIC_SELECT_MENU_TYPE: &str = "multi_static_select";
+pub(crate) const USERS_SELECT_MENU_TYPE: &str = "users_select";
+pub(crate) const CONVERSATIONS_SELECT_MENU_TYPE: &str = "conversations_select";
+pub(crate) const CHANNELS_SELECT_MENU_TYPE: &str = "channels_select";
+pub(crate) const MULTI_USERS_SELECT_MENU_TYPE: &str = "multi_users_select";
+pub(crate) const MULTI_CONVERSATIONS_SELECT_MENU_TYPE: &str = "multi_conversations_select";
+pub(crate) const MULTI_CHANNELS_SELECT_MENU_TYPE: &str = "multi_channels_select";
 
 /// ButtonElement
 /// OverflowMenuElement
 /// PlainTextInputElement
+/// EmailInputElement, UrlInputElement, NumberInputElement
 /// StaticSelectMenuElement
-#[derive(Debug, Serialize)]
+/// ExternalSelectMenuElement
+/// UsersSelectMenuElement, ConversationsSelectMenuElement, ChannelsSelectMenuElement
+/// and their multi-select versions
+/// CheckboxesElement
+/// RadioButtonsElement
+/// DatePickerElement, TimePickerElement, DateTimePickerElement
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum BlockElement {
     Button(ButtonElement),
     OverflowMenu(OverflowMenuElement),
     PlainTextInput(PlainTextInputElement),
+    EmailInput(EmailInputElement),
+    UrlInput(UrlInputElement),
+    NumberInput(NumberInputElement),
     StaticSelectMenu(StaticSelectMenuElement),
+    ExternalSelectMenu(ExternalSelectMenuElement),
     MultiStaticSelectMenu(MultiStaticSelectMenuElement),
+    UsersSelectMenu(UsersSelectMenuElement),
+    ConversationsSelectMenu(ConversationsSelectMenuElement),
+    ChannelsSelectMenu(ChannelsSelectMenuElement),
+    MultiUsersSelectMenu(MultiUsersSelectMenuElement),
+    MultiConversationsSelectMenu(MultiConversationsSelectMenuElement),
+    MultiChannelsSelectMenu(MultiChannelsSelectMenuElement),
     Image(ImageElement),
     DatePicker(DatePickerElement),
+    TimePicker(TimePickerElement),
+    DateTimePicker(DateTimePickerElement),
+    Checkboxes(CheckboxesElement),
+    RadioButtons(RadioButtonsElement),
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for BlockElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let element = match type_name.as_str() {
+            BUTTON_TYPE => from_value(value).map(Button),
+            DATE_PICKER_TYPE => from_value(value).map(DatePicker),
+            TIME_PICKER_TYPE => from_value(value).map(TimePicker),
+            DATE_TIME_PICKER_TYPE => from_value(value).map(DateTimePicker),
+            CHECKBOXES_TYPE => from_value(value).map(Checkboxes),
+            RADIO_BUTTONS_TYPE => from_value(value).map(RadioButtons),
+            IMAGE_TYPE => from_value(value).map(Image),
+            OVERFLOW_MENU_TYPE => from_value(value).map(OverflowMenu),
+            PLAIN_TEXT_INPUT_TYPE => from_value(value).map(PlainTextInput),
+            EMAIL_TEXT_INPUT_TYPE => from_value(value).map(EmailInput),
+            URL_TEXT_INPUT_TYPE => from_value(value).map(UrlInput),
+            NUMBER_INPUT_TYPE => from_value(value).map(NumberInput),
+            STATIC_SELECT_MENU_TYPE => from_value(value).map(StaticSelectMenu),
+            EXTERNAL_SELECT_MENU_TYPE => from_value(value).map(ExternalSelectMenu),
+            MULTI_STATIC_SELECT_MENU_TYPE => from_value(value).map(MultiStaticSelectMenu),
+            USERS_SELECT_MENU_TYPE => from_value(value).map(UsersSelectMenu),
+            CONVERSATIONS_SELECT_MENU_TYPE => from_value(value).map(ConversationsSelectMenu),
+            CHANNELS_SELECT_MENU_TYPE => from_value(value).map(ChannelsSelectMenu),
+            MULTI_USERS_SELECT_MENU_TYPE => from_value(value).map(MultiUsersSelectMenu),
+            MULTI_CONVERSATIONS_SELECT_MENU_TYPE => {
+                from_value(value).map(MultiConversationsSelectMenu)
+            }
+            MULTI_CHANNELS_SELECT_MENU_TYPE => from_value(value).map(MultiChannelsSelectMenu),
+            _ => Ok(Unknown(value)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+impl Validate for BlockElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        match self {
+            Button(element) => element.collect_violations(path, violations),
+            OverflowMenu(element) => element.collect_violations(path, violations),
+            PlainTextInput(element) => element.collect_violations(path, violations),
+            EmailInput(element) => element.collect_violations(path, violations),
+            UrlInput(element) => element.collect_violations(path, violations),
+            NumberInput(element) => element.collect_violations(path, violations),
+            StaticSelectMenu(element) => element.collect_violations(path, violations),
+            ExternalSelectMenu(element) => element.collect_violations(path, violations),
+            MultiStaticSelectMenu(element) => element.collect_violations(path, violations),
+            UsersSelectMenu(element) => element.collect_violations(path, violations),
+            ConversationsSelectMenu(element) => element.collect_violations(path, violations),
+            ChannelsSelectMenu(element) => element.collect_violations(path, violations),
+            MultiUsersSelectMenu(element) => element.collect_violations(path, violations),
+            MultiConversationsSelectMenu(element) => element.collect_violations(path, violations),
+            MultiChannelsSelectMenu(element) => element.collect_violations(path, violations),
+            Image(_) => {}
+            DatePicker(element) => element.collect_violations(path, violations),
+            TimePicker(element) => element.collect_violations(path, violations),
+            DateTimePicker(element) => element.collect_violations(path, violations),
+            Checkboxes(element) => element.collect_violations(path, violations),
+            RadioButtons(element) => element.collect_violations(path, violations),
+            Unknown(_) => {}
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::block_element::overflow_menu::OverflowMenuOption::TwoOptions;
-    use crate::block_element::BlockElement::{Button, OverflowMenu};
     use crate::composition::option::OptionObject;
     use crate::composition::text::PlainText;
 
@@ -97,4 +228,79 @@ mod test {
 
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_round_trip() {
+        let option1 = OptionObject::new(PlainText::new("text1"), "value1");
+        let option2 = OptionObject::new(PlainText::new("text2"), "value2");
+        let elements = vec![
+            Button(ButtonElement::new(PlainText::new("text"), "action_id").value("value")),
+            OverflowMenu(OverflowMenuElement::new(
+                "action",
+                TwoOptions([option1.clone(), option2.clone()]),
+            )),
+            PlainTextInput(PlainTextInputElement::new("action_id")),
+            EmailInput(EmailInputElement::new("action_id").initial_value("user@example.com")),
+            UrlInput(UrlInputElement::new("action_id").focus_on_load(true)),
+            NumberInput(NumberInputElement::new("action_id", false).max_value("10")),
+            StaticSelectMenu(
+                StaticSelectMenuElement::new("placeholder", "action_id")
+                    .options(vec![option1.clone(), option2.clone()])
+                    .initial_option(option1.clone()),
+            ),
+            ExternalSelectMenu(
+                ExternalSelectMenuElement::new("placeholder", "action_id").min_query_length(0),
+            ),
+            UsersSelectMenu(UsersSelectMenuElement::new("placeholder", "action_id")),
+            ConversationsSelectMenu(
+                ConversationsSelectMenuElement::new("placeholder", "action_id")
+                    .default_to_current_conversation(true),
+            ),
+            ChannelsSelectMenu(
+                ChannelsSelectMenuElement::new("placeholder", "action_id").initial_channel("C123"),
+            ),
+            MultiUsersSelectMenu(
+                MultiUsersSelectMenuElement::new("placeholder", "action_id").max_selected_items(2),
+            ),
+            MultiConversationsSelectMenu(MultiConversationsSelectMenuElement::new(
+                "placeholder",
+                "action_id",
+            )),
+            MultiChannelsSelectMenu(
+                MultiChannelsSelectMenuElement::new("placeholder", "action_id")
+                    .initial_channels(vec!["C123".to_string()]),
+            ),
+            MultiStaticSelectMenu(
+                MultiStaticSelectMenuElement::new("placeholder", "action_id")
+                    .options(vec![option1.clone(), option2])
+                    .initial_options(vec![option1]),
+            ),
+            Image(ImageElement::new("image_url", "alt_text")),
+            DatePicker(
+                DatePickerElement::new("action_id").initial_date("2020-01-01".parse().unwrap()),
+            ),
+            Checkboxes(CheckboxesElement::new(
+                "action_id",
+                vec![OptionObject::new("text", "value").description("description")],
+            )),
+            RadioButtons(RadioButtonsElement::new(
+                "action_id",
+                vec![OptionObject::new("text", "value")],
+            )),
+            TimePicker(TimePickerElement::new("action_id").initial_time("12:00".parse().unwrap())),
+            DateTimePicker(DateTimePickerElement::new("action_id").initial_date_time(1628633820)),
+        ];
+        let json = serde_json::to_string(&elements).unwrap();
+        let decoded = serde_json::from_str::<Vec<BlockElement>>(&json).unwrap();
+        assert_eq!(decoded, elements);
+    }
+
+    #[test]
+    fn test_de_unknown_type() {
+        let json = r#"{"type":"workflow_button","action_id":"action_id"}"#;
+        let element = serde_json::from_str::<BlockElement>(json).unwrap();
+        let value: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(element, Unknown(value.clone()));
+        assert_eq!(serde_json::to_value(&element).unwrap(), value);
+    }
 }
@@ -1,10 +1,17 @@
-use crate::block_element::MULTI_STATIC_SELECT_MENU_TYPE;
+use crate::block_element::{
+    MULTI_CHANNELS_SELECT_MENU_TYPE, MULTI_CONVERSATIONS_SELECT_MENU_TYPE,
+    MULTI_STATIC_SELECT_MENU_TYPE, MULTI_USERS_SELECT_MENU_TYPE,
+};
 use crate::composition::confirmation_dialog::ConfirmationDialog;
+use crate::composition::filter::Filter;
 use crate::composition::option::OptionObject;
 use crate::composition::option_group::OptionGroup;
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{
+    check_count, check_length, check_min, check_text, field, Validate, Violation,
+};
+use serde::{Deserialize, Serialize};
 
 /// `initial_options`: Set an array of option objects that exactly match one or more op the options
 /// with in `options` or `option_groups`
@@ -47,17 +54,17 @@ use serde::Serialize;
 /// //     OptionObject::new(PlainText::new("t3"), "v3"),
 /// // ]);
 /// ```
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MultiStaticSelectMenuElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     placeholder: Text,
     action_id: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     options: Vec<OptionObject>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     option_groups: Vec<OptionGroup>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     initial_options: Vec<OptionObject>,
     #[serde(skip_serializing_if = "Option::is_none")]
     confirm: Option<ConfirmationDialog>,
@@ -66,7 +73,7 @@ pub struct MultiStaticSelectMenuElement {
 impl MultiStaticSelectMenuElement {
     pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
         MultiStaticSelectMenuElement {
-            type_name: MULTI_STATIC_SELECT_MENU_TYPE,
+            type_name: MULTI_STATIC_SELECT_MENU_TYPE.to_string(),
             placeholder: Plain(placeholder.into()),
             action_id: action_id.into(),
             options: Vec::default(),
@@ -97,9 +104,261 @@ impl MultiStaticSelectMenuElement {
     }
 }
 
+impl Validate for MultiStaticSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        check_count(field(path, "options"), &self.options, 100, violations);
+        self.options
+            .collect_violations(&field(path, "options"), violations);
+        check_count(
+            field(path, "option_groups"),
+            &self.option_groups,
+            100,
+            violations,
+        );
+        self.option_groups
+            .collect_violations(&field(path, "option_groups"), violations);
+        self.initial_options
+            .collect_violations(&field(path, "initial_options"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Multi-select version of `UsersSelectMenuElement`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultiUsersSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    initial_users: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_selected_items: Option<u32>,
+}
+
+impl MultiUsersSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        MultiUsersSelectMenuElement {
+            type_name: MULTI_USERS_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_users: Vec::default(),
+            confirm: Option::default(),
+            max_selected_items: Option::default(),
+        }
+    }
+
+    /// User IDs of any valid users.
+    pub fn initial_users(mut self, initial_users: Vec<String>) -> Self {
+        self.initial_users = initial_users;
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Minimum number is 1.
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+}
+
+impl Validate for MultiUsersSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(max_selected_items) = self.max_selected_items {
+            check_min(
+                field(path, "max_selected_items"),
+                max_selected_items as usize,
+                1,
+                violations,
+            );
+        }
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Multi-select version of `ConversationsSelectMenuElement`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultiConversationsSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    initial_conversations: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_to_current_conversation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Filter>,
+}
+
+impl MultiConversationsSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        MultiConversationsSelectMenuElement {
+            type_name: MULTI_CONVERSATIONS_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_conversations: Vec::default(),
+            default_to_current_conversation: Option::default(),
+            confirm: Option::default(),
+            max_selected_items: Option::default(),
+            filter: Option::default(),
+        }
+    }
+
+    /// IDs of any valid conversations.
+    pub fn initial_conversations(mut self, initial_conversations: Vec<String>) -> Self {
+        self.initial_conversations = initial_conversations;
+        self
+    }
+
+    /// Pre-populates the conversation which the menu is in. Ignored if `initial_conversations` is set.
+    pub fn default_to_current_conversation(
+        mut self,
+        default_to_current_conversation: bool,
+    ) -> Self {
+        self.default_to_current_conversation = Some(default_to_current_conversation);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Minimum number is 1.
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+impl Validate for MultiConversationsSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(max_selected_items) = self.max_selected_items {
+            check_min(
+                field(path, "max_selected_items"),
+                max_selected_items as usize,
+                1,
+                violations,
+            );
+        }
+        self.filter
+            .collect_violations(&field(path, "filter"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Multi-select version of `ChannelsSelectMenuElement`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultiChannelsSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    initial_channels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_selected_items: Option<u32>,
+}
+
+impl MultiChannelsSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        MultiChannelsSelectMenuElement {
+            type_name: MULTI_CHANNELS_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_channels: Vec::default(),
+            confirm: Option::default(),
+            max_selected_items: Option::default(),
+        }
+    }
+
+    /// IDs of any valid public channels.
+    pub fn initial_channels(mut self, initial_channels: Vec<String>) -> Self {
+        self.initial_channels = initial_channels;
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Minimum number is 1.
+    pub fn max_selected_items(mut self, max_selected_items: u32) -> Self {
+        self.max_selected_items = Some(max_selected_items);
+        self
+    }
+}
+
+impl Validate for MultiChannelsSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(max_selected_items) = self.max_selected_items {
+            check_min(
+                field(path, "max_selected_items"),
+                max_selected_items as usize,
+                1,
+                violations,
+            );
+        }
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::composition::filter::ConversationType;
+    use crate::validation::ViolationKind::TooSmall;
 
     #[test]
     fn test_ser_new() {
@@ -246,4 +505,90 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_ser_multi_users_all() {
+        let multi = MultiUsersSelectMenuElement::new("placeholder", "action_id")
+            .initial_users(vec!["U123".to_string(), "U456".to_string()])
+            .max_selected_items(3);
+        let json = serde_json::to_string_pretty(&multi).unwrap();
+
+        let expected = r#"{
+  "type": "multi_users_select",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "action_id": "action_id",
+  "initial_users": [
+    "U123",
+    "U456"
+  ],
+  "max_selected_items": 3
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_multi_conversations_filter() {
+        let multi = MultiConversationsSelectMenuElement::new("placeholder", "action_id")
+            .filter(Filter::new().exclude_external_shared_channels(true));
+        let json = serde_json::to_string_pretty(&multi).unwrap();
+
+        let expected = r#"{
+  "type": "multi_conversations_select",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "action_id": "action_id",
+  "filter": {
+    "exclude_external_shared_channels": true
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_validate_max_selected_items() {
+        let multi =
+            MultiUsersSelectMenuElement::new("placeholder", "action_id").max_selected_items(1);
+        assert_eq!(multi.validate(), Ok(()));
+
+        let multi =
+            MultiUsersSelectMenuElement::new("placeholder", "action_id").max_selected_items(0);
+        let violations = multi.validate().unwrap_err();
+        assert_eq!(
+            violations,
+            vec![Violation {
+                path: "max_selected_items".to_string(),
+                kind: TooSmall { min: 1, actual: 0 },
+            }]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "max_selected_items: must be at least 1, but was 0"
+        );
+
+        let multi = MultiConversationsSelectMenuElement::new("placeholder", "action_id")
+            .max_selected_items(0);
+        assert!(multi.validate().is_err());
+
+        let multi =
+            MultiChannelsSelectMenuElement::new("placeholder", "action_id").max_selected_items(0);
+        assert!(multi.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_filter() {
+        let multi = MultiConversationsSelectMenuElement::new("placeholder", "action_id")
+            .filter(Filter::new().include(vec![ConversationType::Public]));
+        assert_eq!(multi.validate(), Ok(()));
+
+        let multi = MultiConversationsSelectMenuElement::new("placeholder", "action_id")
+            .filter(Filter::new());
+        let violations = multi.validate().unwrap_err();
+        assert_eq!(violations[0].path, "filter");
+        assert_eq!(violations[0].to_string(), "filter: must not be empty");
+    }
 }
@@ -0,0 +1,330 @@
+use crate::block_element::NUMBER_INPUT_TYPE;
+use crate::composition::dispatch_action_config::DispatchActionConfig;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::validation::{check_length, check_text, field, Validate, Violation, ViolationKind};
+use serde::{Deserialize, Serialize};
+
+/// A plain-text input which only accepts a number.
+///
+/// `is_decimal_allowed`: Whether decimal numbers are accepted, or only integers.
+///
+/// `min_value`, `max_value` and `initial_value` are numbers in strings.
+/// `Validate` checks that they are integers unless `is_decimal_allowed`,
+/// that `min_value` is at most `max_value`,
+/// and that `initial_value` is between them.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::block_element::number_input::NumberInputElement;
+/// use block_kit::validation::Validate;
+///
+/// let input = NumberInputElement::new("action_id", false)
+///     .min_value("10")
+///     .max_value("5")
+///     .initial_value("1.5");
+///
+/// let violations = input.validate().unwrap_err();
+/// assert_eq!(violations[0].to_string(), "initial_value: must be an integer, but was `1.5`");
+/// assert_eq!(violations[1].to_string(), "min_value: must be at most 5, but was 10");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumberInputElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    action_id: String,
+    is_decimal_allowed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<Text>,
+}
+
+impl NumberInputElement {
+    pub fn new(action_id: impl Into<String>, is_decimal_allowed: bool) -> Self {
+        NumberInputElement {
+            type_name: NUMBER_INPUT_TYPE.to_string(),
+            action_id: action_id.into(),
+            is_decimal_allowed,
+            min_value: Option::default(),
+            max_value: Option::default(),
+            initial_value: Option::default(),
+            dispatch_action_config: Option::default(),
+            focus_on_load: Option::default(),
+            placeholder: Option::default(),
+        }
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn dispatch_action_config(mut self, dispatch_action_config: DispatchActionConfig) -> Self {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+
+    /// Only one element in a view can have `focus_on_load` set to true.
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<PlainText>) -> Self {
+        self.placeholder = Some(Plain(placeholder.into()));
+        self
+    }
+
+    /// Must be less than or equal to `max_value`.
+    pub fn min_value(mut self, min_value: impl Into<String>) -> Self {
+        self.min_value = Some(min_value.into());
+        self
+    }
+
+    /// Must be greater than or equal to `min_value`.
+    pub fn max_value(mut self, max_value: impl Into<String>) -> Self {
+        self.max_value = Some(max_value.into());
+        self
+    }
+}
+
+impl Validate for NumberInputElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(placeholder) = &self.placeholder {
+            check_text(&field(path, "placeholder"), placeholder, 150, violations);
+        }
+        let min_value = self.check_number(field(path, "min_value"), &self.min_value, violations);
+        let max_value = self.check_number(field(path, "max_value"), &self.max_value, violations);
+        let initial_value = self.check_number(
+            field(path, "initial_value"),
+            &self.initial_value,
+            violations,
+        );
+        if let (Some(min), Some(max)) = (min_value, max_value) {
+            if min > max {
+                violations.push(Violation {
+                    path: field(path, "min_value"),
+                    kind: ViolationKind::NumberTooLarge {
+                        max: self.max_value.clone().unwrap_or_default(),
+                        actual: self.min_value.clone().unwrap_or_default(),
+                    },
+                });
+            }
+        }
+        if let Some(initial) = initial_value {
+            if min_value.map_or(false, |min| initial < min) {
+                violations.push(Violation {
+                    path: field(path, "initial_value"),
+                    kind: ViolationKind::NumberTooSmall {
+                        min: self.min_value.clone().unwrap_or_default(),
+                        actual: self.initial_value.clone().unwrap_or_default(),
+                    },
+                });
+            }
+            if max_value.map_or(false, |max| initial > max) {
+                violations.push(Violation {
+                    path: field(path, "initial_value"),
+                    kind: ViolationKind::NumberTooLarge {
+                        max: self.max_value.clone().unwrap_or_default(),
+                        actual: self.initial_value.clone().unwrap_or_default(),
+                    },
+                });
+            }
+        }
+    }
+}
+
+impl NumberInputElement {
+    /// Returns the number in `value`, or reports it if it's not a number allowed by `is_decimal_allowed`.
+    fn check_number(
+        &self,
+        path: String,
+        value: &Option<String>,
+        violations: &mut Vec<Violation>,
+    ) -> Option<f64> {
+        let value = value.as_ref()?;
+        let number = if self.is_decimal_allowed {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+        } else {
+            value.parse::<i64>().ok().map(|number| number as f64)
+        };
+        if number.is_none() {
+            violations.push(Violation {
+                path,
+                kind: ViolationKind::NotNumber {
+                    value: value.clone(),
+                    integer: !self.is_decimal_allowed,
+                },
+            });
+        }
+        number
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::composition::dispatch_action_config::TriggerAction;
+    use crate::validation::ViolationKind::{NotNumber, NumberTooLarge, NumberTooSmall};
+
+    #[test]
+    fn test_ser_new() {
+        let input = NumberInputElement::new("action_id", true);
+        let json = serde_json::to_string_pretty(&input).unwrap();
+
+        let expected = r#"{
+  "type": "number_input",
+  "action_id": "action_id",
+  "is_decimal_allowed": true
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_all() {
+        let input = NumberInputElement::new("action_id", true)
+            .min_value("0")
+            .max_value("10")
+            .initial_value("1.5")
+            .dispatch_action_config(DispatchActionConfig::new(vec![
+                TriggerAction::OnEnterPressed,
+            ]))
+            .focus_on_load(true)
+            .placeholder("placeholder");
+        let json = serde_json::to_string_pretty(&input).unwrap();
+
+        let expected = r#"{
+  "type": "number_input",
+  "action_id": "action_id",
+  "is_decimal_allowed": true,
+  "min_value": "0",
+  "max_value": "10",
+  "initial_value": "1.5",
+  "dispatch_action_config": {
+    "trigger_actions_on": [
+      "on_enter_pressed"
+    ]
+  },
+  "focus_on_load": true,
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = NumberInputElement::new("action_id", true)
+            .min_value("0")
+            .max_value("10")
+            .initial_value("1.5")
+            .placeholder("placeholder");
+        let json = serde_json::to_string(&input).unwrap();
+        let decoded = serde_json::from_str::<NumberInputElement>(&json).unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = NumberInputElement::new("action_id", true)
+            .min_value("-1.5")
+            .max_value("10")
+            .initial_value("2.25");
+        assert_eq!(input.validate(), Ok(()));
+
+        let input = NumberInputElement::new("action_id", false)
+            .min_value("1.5")
+            .max_value("ten")
+            .initial_value("3");
+        assert_eq!(
+            input.validate(),
+            Err(vec![
+                Violation {
+                    path: "min_value".to_string(),
+                    kind: NotNumber {
+                        value: "1.5".to_string(),
+                        integer: true
+                    },
+                },
+                Violation {
+                    path: "max_value".to_string(),
+                    kind: NotNumber {
+                        value: "ten".to_string(),
+                        integer: true
+                    },
+                },
+            ])
+        );
+
+        let input = NumberInputElement::new("action_id", true)
+            .min_value("10")
+            .max_value("9.5");
+        assert_eq!(
+            input.validate(),
+            Err(vec![Violation {
+                path: "min_value".to_string(),
+                kind: NumberTooLarge {
+                    max: "9.5".to_string(),
+                    actual: "10".to_string()
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_initial_value() {
+        let input = NumberInputElement::new("action_id", false)
+            .min_value("1")
+            .max_value("10")
+            .initial_value("10");
+        assert_eq!(input.validate(), Ok(()));
+
+        let input = NumberInputElement::new("action_id", false)
+            .min_value("1")
+            .initial_value("0");
+        let violations = input.validate().unwrap_err();
+        assert_eq!(
+            violations,
+            vec![Violation {
+                path: "initial_value".to_string(),
+                kind: NumberTooSmall {
+                    min: "1".to_string(),
+                    actual: "0".to_string()
+                },
+            }]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "initial_value: must be at least 1, but was 0"
+        );
+
+        let input = NumberInputElement::new("action_id", true)
+            .max_value("2.5")
+            .initial_value("3");
+        assert_eq!(
+            input.validate(),
+            Err(vec![Violation {
+                path: "initial_value".to_string(),
+                kind: NumberTooLarge {
+                    max: "2.5".to_string(),
+                    actual: "3".to_string()
+                },
+            }])
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use crate::block_element::OVERFLOW_MENU_TYPE;
 use crate::composition::confirmation_dialog::ConfirmationDialog;
 use crate::composition::option::OptionObject;
-use serde::Serialize;
+use crate::validation::{check_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone)]
 pub enum OverflowMenuOption {
@@ -11,10 +12,10 @@ pub enum OverflowMenuOption {
     FiveOptions([OptionObject; 5]),
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OverflowMenuElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     action_id: String,
     options: Vec<OptionObject>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -31,7 +32,7 @@ impl OverflowMenuElement {
         };
 
         OverflowMenuElement {
-            type_name: OVERFLOW_MENU_TYPE,
+            type_name: OVERFLOW_MENU_TYPE.to_string(),
             action_id: action_id.into(),
             options,
             confirm: Option::default(),
@@ -44,6 +45,16 @@ impl OverflowMenuElement {
     }
 }
 
+impl Validate for OverflowMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        self.options
+            .collect_violations(&field(path, "options"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,13 +1,43 @@
 use crate::block_element::PLAIN_TEXT_INPUT_TYPE;
-use crate::composition::text::Text;
-use serde::Serialize;
+use crate::composition::dispatch_action_config::DispatchActionConfig;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::validation::{check_length, check_max, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+const MAX_LENGTH: u32 = 3000;
 
 /// WARNING
 /// Plain-text input elements are currently only available in modals
-#[derive(Debug, Serialize)]
+///
+/// `min_length` and `max_length`: Maximum is 3000,
+/// and `min_length` must be at most `max_length`.
+/// Their setters reject values out of the range,
+/// and `Validate` checks them for deserialized elements.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::block_element::plain_text_input::{LengthError, PlainTextInputElement};
+///
+/// let input = PlainTextInputElement::new("action_id")
+///     .multiline(true)
+///     .max_length(10)
+///     .unwrap();
+///
+/// let error = input.min_length(100).unwrap_err();
+/// assert_eq!(
+///     error,
+///     LengthError::MinGreaterThanMax {
+///         min_length: 100,
+///         max_length: 10
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlainTextInputElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     action_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     placeholder: Option<Text>,
@@ -19,18 +49,145 @@ pub struct PlainTextInputElement {
     min_length: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     max_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus_on_load: Option<bool>,
 }
 
 impl PlainTextInputElement {
     pub fn new(action_id: impl Into<String>) -> Self {
         PlainTextInputElement {
-            type_name: PLAIN_TEXT_INPUT_TYPE,
+            type_name: PLAIN_TEXT_INPUT_TYPE.to_string(),
             action_id: action_id.into(),
             placeholder: Option::default(),
             initial_value: Option::default(),
             multiline: Option::default(),
             min_length: Option::default(),
             max_length: Option::default(),
+            dispatch_action_config: Option::default(),
+            focus_on_load: Option::default(),
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<PlainText>) -> Self {
+        self.placeholder = Some(Plain(placeholder.into()));
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = Some(multiline);
+        self
+    }
+
+    pub fn min_length(mut self, min_length: u32) -> Result<Self, LengthError> {
+        let max_length = self.max_length.unwrap_or(MAX_LENGTH);
+        check_lengths(min_length, max_length)?;
+        self.min_length = Some(min_length);
+        Ok(self)
+    }
+
+    pub fn max_length(mut self, max_length: u32) -> Result<Self, LengthError> {
+        let min_length = self.min_length.unwrap_or_default();
+        check_lengths(min_length, max_length)?;
+        self.max_length = Some(max_length);
+        Ok(self)
+    }
+
+    pub fn dispatch_action_config(mut self, dispatch_action_config: DispatchActionConfig) -> Self {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+
+    /// Only one element in a view can have `focus_on_load` set to true.
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+}
+
+fn check_lengths(min_length: u32, max_length: u32) -> Result<(), LengthError> {
+    if min_length > MAX_LENGTH {
+        return Err(LengthError::TooLarge(min_length));
+    }
+    if max_length > MAX_LENGTH {
+        return Err(LengthError::TooLarge(max_length));
+    }
+    if min_length > max_length {
+        return Err(LengthError::MinGreaterThanMax {
+            min_length,
+            max_length,
+        });
+    }
+    Ok(())
+}
+
+/// Returned by `PlainTextInputElement::min_length` and `max_length` for values out of the range.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LengthError {
+    /// The length is greater than 3000.
+    TooLarge(u32),
+    MinGreaterThanMax {
+        min_length: u32,
+        max_length: u32,
+    },
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthError::TooLarge(length) => write!(
+                f,
+                "length must be at most {}, but was {}",
+                MAX_LENGTH, length
+            ),
+            LengthError::MinGreaterThanMax {
+                min_length,
+                max_length,
+            } => write!(
+                f,
+                "min_length {} is greater than max_length {}",
+                min_length, max_length
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LengthError {}
+
+impl Validate for PlainTextInputElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(placeholder) = &self.placeholder {
+            check_text(&field(path, "placeholder"), placeholder, 150, violations);
+        }
+        let max_length = self.max_length.unwrap_or(MAX_LENGTH);
+        if let Some(min_length) = self.min_length {
+            check_max(
+                field(path, "min_length"),
+                min_length as usize,
+                max_length.min(MAX_LENGTH) as usize,
+                violations,
+            );
+        }
+        check_max(
+            field(path, "max_length"),
+            max_length as usize,
+            MAX_LENGTH as usize,
+            violations,
+        );
+        if let Some(initial_value) = &self.initial_value {
+            check_length(
+                field(path, "initial_value"),
+                initial_value,
+                max_length.min(MAX_LENGTH) as usize,
+                violations,
+            );
         }
     }
 }
@@ -38,6 +195,8 @@ impl PlainTextInputElement {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::composition::dispatch_action_config::TriggerAction;
+    use crate::validation::ViolationKind::{TooLarge, TooLong};
 
     #[test]
     fn test_ser_new() {
@@ -50,4 +209,142 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_ser_all() {
+        let input = PlainTextInputElement::new("action_id")
+            .placeholder("placeholder")
+            .initial_value("initial")
+            .multiline(true)
+            .min_length(1)
+            .unwrap()
+            .max_length(500)
+            .unwrap()
+            .dispatch_action_config(DispatchActionConfig::new(vec![
+                TriggerAction::OnCharacterEntered,
+            ]))
+            .focus_on_load(true);
+        let json = serde_json::to_string_pretty(&input).unwrap();
+
+        let expected = r#"{
+  "type": "plain_text_input",
+  "action_id": "action_id",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "initial_value": "initial",
+  "multiline": true,
+  "min_length": 1,
+  "max_length": 500,
+  "dispatch_action_config": {
+    "trigger_actions_on": [
+      "on_character_entered"
+    ]
+  },
+  "focus_on_load": true
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = PlainTextInputElement::new("action_id")
+            .initial_value("initial")
+            .max_length(10)
+            .unwrap();
+        let json = serde_json::to_string(&input).unwrap();
+        let decoded = serde_json::from_str::<PlainTextInputElement>(&json).unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn test_lengths() {
+        let input = PlainTextInputElement::new("action_id")
+            .min_length(10)
+            .unwrap()
+            .max_length(3000)
+            .unwrap();
+        assert_eq!(input.validate(), Ok(()));
+
+        assert_eq!(
+            PlainTextInputElement::new("action_id").min_length(3001),
+            Err(LengthError::TooLarge(3001))
+        );
+        assert_eq!(
+            PlainTextInputElement::new("action_id").max_length(3001),
+            Err(LengthError::TooLarge(3001))
+        );
+        assert_eq!(
+            PlainTextInputElement::new("action_id")
+                .min_length(6)
+                .unwrap()
+                .max_length(5),
+            Err(LengthError::MinGreaterThanMax {
+                min_length: 6,
+                max_length: 5
+            })
+        );
+        assert_eq!(
+            PlainTextInputElement::new("action_id")
+                .max_length(5)
+                .unwrap()
+                .min_length(6)
+                .map_err(|error| error.to_string()),
+            Err("min_length 6 is greater than max_length 5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let json = r#"{
+  "type": "plain_text_input",
+  "action_id": "action_id",
+  "initial_value": "aaaaaa",
+  "min_length": 6,
+  "max_length": 5
+}"#;
+        let input = serde_json::from_str::<PlainTextInputElement>(json).unwrap();
+        assert_eq!(
+            input.validate(),
+            Err(vec![
+                Violation {
+                    path: "min_length".to_string(),
+                    kind: TooLarge { max: 5, actual: 6 },
+                },
+                Violation {
+                    path: "initial_value".to_string(),
+                    kind: TooLong { max: 5, actual: 6 },
+                },
+            ])
+        );
+
+        let json =
+            r#"{ "type": "plain_text_input", "action_id": "action_id", "min_length": 3001 }"#;
+        let input = serde_json::from_str::<PlainTextInputElement>(json).unwrap();
+        assert_eq!(
+            input.validate(),
+            Err(vec![Violation {
+                path: "min_length".to_string(),
+                kind: TooLarge {
+                    max: 3000,
+                    actual: 3001
+                },
+            }])
+        );
+
+        let json =
+            r#"{ "type": "plain_text_input", "action_id": "action_id", "max_length": 3001 }"#;
+        let input = serde_json::from_str::<PlainTextInputElement>(json).unwrap();
+        assert_eq!(
+            input.validate(),
+            Err(vec![Violation {
+                path: "max_length".to_string(),
+                kind: TooLarge {
+                    max: 3000,
+                    actual: 3001
+                },
+            }])
+        );
+    }
 }
@@ -0,0 +1,118 @@
+use crate::block_element::RADIO_BUTTONS_TYPE;
+use crate::composition::confirmation_dialog::ConfirmationDialog;
+use crate::composition::option::OptionObject;
+use crate::validation::{check_count, check_length, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// A radio button group that allows a user to choose one item from a list of possible options.
+///
+/// `options`: Maximum number of options is 10.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RadioButtonsElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    action_id: String,
+    options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl RadioButtonsElement {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        RadioButtonsElement {
+            type_name: RADIO_BUTTONS_TYPE.to_string(),
+            action_id: action_id.into(),
+            options,
+            initial_option: Option::default(),
+            confirm: Option::default(),
+        }
+    }
+
+    /// Must exactly match one of the `options`.
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+impl Validate for RadioButtonsElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        check_count(field(path, "options"), &self.options, 10, violations);
+        self.options
+            .collect_violations(&field(path, "options"), violations);
+        self.initial_option
+            .collect_violations(&field(path, "initial_option"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::ViolationKind::TooMany;
+
+    #[test]
+    fn test_ser_all() {
+        let option1 = OptionObject::new("text1", "value1");
+        let option2 = OptionObject::new("text2", "value2");
+        let element = RadioButtonsElement::new("action_id", vec![option1.clone(), option2])
+            .initial_option(option1);
+        let json = serde_json::to_string_pretty(&element).unwrap();
+
+        let expected = r#"{
+  "type": "radio_buttons",
+  "action_id": "action_id",
+  "options": [
+    {
+      "text": {
+        "type": "plain_text",
+        "text": "text1"
+      },
+      "value": "value1"
+    },
+    {
+      "text": {
+        "type": "plain_text",
+        "text": "text2"
+      },
+      "value": "value2"
+    }
+  ],
+  "initial_option": {
+    "text": {
+      "type": "plain_text",
+      "text": "text1"
+    },
+    "value": "value1"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let options = (0..11)
+            .map(|i| OptionObject::new("text", format!("value{}", i)))
+            .collect();
+        let element = RadioButtonsElement::new("action_id", options);
+        assert_eq!(
+            element.validate(),
+            Err(vec![Violation {
+                path: "options".to_string(),
+                kind: TooMany {
+                    max: 10,
+                    actual: 11
+                },
+            }])
+        );
+    }
+}
@@ -1,21 +1,26 @@
-use crate::block_element::STATIC_SELECT_MENU_TYPE;
+use crate::block_element::{
+    CHANNELS_SELECT_MENU_TYPE, CONVERSATIONS_SELECT_MENU_TYPE, EXTERNAL_SELECT_MENU_TYPE,
+    STATIC_SELECT_MENU_TYPE, USERS_SELECT_MENU_TYPE,
+};
 use crate::composition::confirmation_dialog::ConfirmationDialog;
+use crate::composition::filter::Filter;
 use crate::composition::option::OptionObject;
 use crate::composition::option_group::OptionGroup;
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{check_count, check_length, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
 /// requires set options or option_groups to display.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StaticSelectMenuElement {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     placeholder: Text,
     action_id: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     options: Vec<OptionObject>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     option_groups: Vec<OptionGroup>,
     #[serde(skip_serializing_if = "Option::is_none")]
     initial_option: Option<OptionObject>,
@@ -26,7 +31,7 @@ pub struct StaticSelectMenuElement {
 impl StaticSelectMenuElement {
     pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
         StaticSelectMenuElement {
-            type_name: STATIC_SELECT_MENU_TYPE,
+            type_name: STATIC_SELECT_MENU_TYPE.to_string(),
             placeholder: Plain(placeholder.into()),
             action_id: action_id.into(),
             options: Vec::default(),
@@ -61,9 +66,290 @@ impl StaticSelectMenuElement {
     }
 }
 
+impl Validate for StaticSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        check_count(field(path, "options"), &self.options, 100, violations);
+        self.options
+            .collect_violations(&field(path, "options"), violations);
+        check_count(
+            field(path, "option_groups"),
+            &self.option_groups,
+            100,
+            violations,
+        );
+        self.option_groups
+            .collect_violations(&field(path, "option_groups"), violations);
+        self.initial_option
+            .collect_violations(&field(path, "initial_option"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Options are loaded from the options load URL of the app,
+/// which receives `BlockSuggestionPayload` and responds `OptionsResponse`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl ExternalSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        ExternalSelectMenuElement {
+            type_name: EXTERNAL_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_option: Option::default(),
+            min_query_length: Option::default(),
+            confirm: Option::default(),
+        }
+    }
+
+    /// Must exactly match one of the options which will be loaded from the options load URL.
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+
+    /// The number of characters typed before querying options. Defaults to 3.
+    pub fn min_query_length(mut self, min_query_length: u32) -> Self {
+        self.min_query_length = Some(min_query_length);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+impl Validate for ExternalSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        self.initial_option
+            .collect_violations(&field(path, "initial_option"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Populated with a list of Slack users visible to the current user in the active workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsersSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl UsersSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        UsersSelectMenuElement {
+            type_name: USERS_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_user: Option::default(),
+            confirm: Option::default(),
+        }
+    }
+
+    /// The user ID of any valid user.
+    pub fn initial_user(mut self, initial_user: impl Into<String>) -> Self {
+        self.initial_user = Some(initial_user.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+impl Validate for UsersSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Populated with a list of public and private channels, DMs, and MPIMs visible to the current user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationsSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_conversation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_to_current_conversation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_url_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<Filter>,
+}
+
+impl ConversationsSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        ConversationsSelectMenuElement {
+            type_name: CONVERSATIONS_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_conversation: Option::default(),
+            default_to_current_conversation: Option::default(),
+            confirm: Option::default(),
+            response_url_enabled: Option::default(),
+            filter: Option::default(),
+        }
+    }
+
+    /// The ID of any valid conversation.
+    pub fn initial_conversation(mut self, initial_conversation: impl Into<String>) -> Self {
+        self.initial_conversation = Some(initial_conversation.into());
+        self
+    }
+
+    /// Pre-populates the conversation which the menu is in. Ignored if `initial_conversation` is set.
+    pub fn default_to_current_conversation(
+        mut self,
+        default_to_current_conversation: bool,
+    ) -> Self {
+        self.default_to_current_conversation = Some(default_to_current_conversation);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Only available in input blocks in modals. Includes `response_urls` in `view_submission`.
+    pub fn response_url_enabled(mut self, response_url_enabled: bool) -> Self {
+        self.response_url_enabled = Some(response_url_enabled);
+        self
+    }
+
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+impl Validate for ConversationsSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        self.filter
+            .collect_violations(&field(path, "filter"), violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+/// Populated with a list of public channels visible to the current user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelsSelectMenuElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    placeholder: Text,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_url_enabled: Option<bool>,
+}
+
+impl ChannelsSelectMenuElement {
+    pub fn new(placeholder: impl Into<PlainText>, action_id: impl Into<String>) -> Self {
+        ChannelsSelectMenuElement {
+            type_name: CHANNELS_SELECT_MENU_TYPE.to_string(),
+            placeholder: Plain(placeholder.into()),
+            action_id: action_id.into(),
+            initial_channel: Option::default(),
+            confirm: Option::default(),
+            response_url_enabled: Option::default(),
+        }
+    }
+
+    /// The ID of any valid public channel.
+    pub fn initial_channel(mut self, initial_channel: impl Into<String>) -> Self {
+        self.initial_channel = Some(initial_channel.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Only available in input blocks in modals. Includes `response_urls` in `view_submission`.
+    pub fn response_url_enabled(mut self, response_url_enabled: bool) -> Self {
+        self.response_url_enabled = Some(response_url_enabled);
+        self
+    }
+}
+
+impl Validate for ChannelsSelectMenuElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(
+            &field(path, "placeholder"),
+            &self.placeholder,
+            150,
+            violations,
+        );
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::composition::filter::ConversationType;
+    use crate::validation::ViolationKind::{Empty, TooMany};
 
     #[test]
     fn test_ser_new() {
@@ -203,6 +489,153 @@ mod test {
       "text": "deny"
     }
   }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let options = (0..101)
+            .map(|i| OptionObject::new("text", format!("value{}", i)))
+            .collect();
+        let menu = StaticSelectMenuElement::new("placeholder", "action_id").options(options);
+        assert_eq!(
+            menu.validate(),
+            Err(vec![Violation {
+                path: "options".to_string(),
+                kind: TooMany {
+                    max: 100,
+                    actual: 101
+                },
+            }])
+        );
+    }
+
+    #[test]
+    fn test_validate_conversations_filter() {
+        let menu =
+            ConversationsSelectMenuElement::new("placeholder", "action_id").filter(Filter::new());
+        assert_eq!(
+            menu.validate(),
+            Err(vec![Violation {
+                path: "filter".to_string(),
+                kind: Empty,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_ser_external_new() {
+        let menu = ExternalSelectMenuElement::new("placeholder", "action_id");
+        let json = serde_json::to_string_pretty(&menu).unwrap();
+
+        let expected = r#"{
+  "type": "external_select",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "action_id": "action_id"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_external_all() {
+        let confirm = ConfirmationDialog::new(
+            PlainText::new("title"),
+            Plain(PlainText::new("text")),
+            PlainText::new("confirm"),
+            PlainText::new("deny"),
+        );
+        let menu = ExternalSelectMenuElement::new("placeholder", "action_id")
+            .initial_option(OptionObject::new("text", "value"))
+            .min_query_length(1)
+            .confirm(confirm);
+        let json = serde_json::to_string_pretty(&menu).unwrap();
+
+        let expected = r#"{
+  "type": "external_select",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "action_id": "action_id",
+  "initial_option": {
+    "text": {
+      "type": "plain_text",
+      "text": "text"
+    },
+    "value": "value"
+  },
+  "min_query_length": 1,
+  "confirm": {
+    "title": {
+      "type": "plain_text",
+      "text": "title"
+    },
+    "text": {
+      "type": "plain_text",
+      "text": "text"
+    },
+    "confirm": {
+      "type": "plain_text",
+      "text": "confirm"
+    },
+    "deny": {
+      "type": "plain_text",
+      "text": "deny"
+    }
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_conversations_all() {
+        let filter = Filter::new()
+            .include(vec![ConversationType::Public])
+            .exclude_bot_users(true);
+        let menu = ConversationsSelectMenuElement::new("placeholder", "action_id")
+            .initial_conversation("C123")
+            .default_to_current_conversation(true)
+            .response_url_enabled(true)
+            .filter(filter);
+        let json = serde_json::to_string_pretty(&menu).unwrap();
+
+        let expected = r#"{
+  "type": "conversations_select",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "action_id": "action_id",
+  "initial_conversation": "C123",
+  "default_to_current_conversation": true,
+  "response_url_enabled": true,
+  "filter": {
+    "include": [
+      "public"
+    ],
+    "exclude_bot_users": true
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_users_initial_user() {
+        let menu = UsersSelectMenuElement::new("placeholder", "action_id").initial_user("U123");
+        let json = serde_json::to_string_pretty(&menu).unwrap();
+
+        let expected = r#"{
+  "type": "users_select",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "action_id": "action_id",
+  "initial_user": "U123"
 }"#;
         assert_eq!(json, expected);
     }
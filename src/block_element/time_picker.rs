@@ -0,0 +1,111 @@
+use crate::block_element::date_time::Time;
+use crate::block_element::TIME_PICKER_TYPE;
+use crate::composition::confirmation_dialog::ConfirmationDialog;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::validation::{check_length, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// `timezone`: IANA name like `America/Los_Angeles`, defaults to the timezone of the user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimePickerElement {
+    #[serde(rename = "type")]
+    type_name: String,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_time: Option<Time>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+}
+
+impl TimePickerElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        TimePickerElement {
+            type_name: TIME_PICKER_TYPE.to_string(),
+            action_id: action_id.into(),
+            placeholder: Option::default(),
+            initial_time: Option::default(),
+            confirm: Option::default(),
+            timezone: Option::default(),
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<PlainText>) -> Self {
+        self.placeholder = Some(Plain(placeholder.into()));
+        self
+    }
+
+    pub fn initial_time(mut self, initial_time: Time) -> Self {
+        self.initial_time = Some(initial_time);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+}
+
+impl Validate for TimePickerElement {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_length(field(path, "action_id"), &self.action_id, 255, violations);
+        if let Some(placeholder) = &self.placeholder {
+            check_text(&field(path, "placeholder"), placeholder, 150, violations);
+        }
+        self.confirm
+            .collect_violations(&field(path, "confirm"), violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_new() {
+        let time_picker = TimePickerElement::new("action_id");
+        let json = serde_json::to_string_pretty(&time_picker).unwrap();
+
+        let expected = r#"{
+  "type": "timepicker",
+  "action_id": "action_id"
+}"#;
+        assert_eq!(json, expected)
+    }
+
+    #[test]
+    fn test_ser_all() {
+        let time_picker = TimePickerElement::new("action_id")
+            .placeholder("placeholder")
+            .initial_time(Time::from_hm(9, 30).unwrap())
+            .timezone("Asia/Tokyo");
+        let json = serde_json::to_string_pretty(&time_picker).unwrap();
+
+        let expected = r#"{
+  "type": "timepicker",
+  "action_id": "action_id",
+  "placeholder": {
+    "type": "plain_text",
+    "text": "placeholder"
+  },
+  "initial_time": "09:30",
+  "timezone": "Asia/Tokyo"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_de_invalid_time() {
+        let json = r#"{ "type": "timepicker", "action_id": "action_id", "initial_time": "9:30" }"#;
+        assert!(serde_json::from_str::<TimePickerElement>(json).is_err());
+    }
+}
@@ -1,10 +1,11 @@
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
 /// An object that defines a dialog that provides a confirmation step to any interactive element.
 /// This dialog will ask the user to confirm their action by offering a confirm and deny buttons.
-#[derive(Debug, Default, Serialize, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConfirmationDialog {
     title: Text,
     text: Text,
@@ -28,6 +29,15 @@ impl ConfirmationDialog {
     }
 }
 
+impl Validate for ConfirmationDialog {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "title"), &self.title, 100, violations);
+        check_text(&field(path, "text"), &self.text, 300, violations);
+        check_text(&field(path, "confirm"), &self.confirm, 30, violations);
+        check_text(&field(path, "deny"), &self.deny, 30, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
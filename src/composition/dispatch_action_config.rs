@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Determines when an input element will return a `block_actions` payload.
+/// The input block must set `dispatch_action` to use it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DispatchActionConfig {
+    trigger_actions_on: Vec<TriggerAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerAction {
+    OnEnterPressed,
+    OnCharacterEntered,
+}
+
+impl DispatchActionConfig {
+    pub fn new(trigger_actions_on: Vec<TriggerAction>) -> Self {
+        DispatchActionConfig { trigger_actions_on }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_new() {
+        let config = DispatchActionConfig::new(vec![
+            TriggerAction::OnEnterPressed,
+            TriggerAction::OnCharacterEntered,
+        ]);
+        let json = serde_json::to_string_pretty(&config).unwrap_or("".to_string());
+        let expected = r#"{
+  "trigger_actions_on": [
+    "on_enter_pressed",
+    "on_character_entered"
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+}
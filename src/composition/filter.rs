@@ -0,0 +1,105 @@
+use crate::validation::{Validate, Violation, ViolationKind};
+use serde::{Deserialize, Serialize};
+
+/// Filters which conversations are listed in conversation lists,
+/// like `conversations_select` or `multi_conversations_select`.
+///
+/// `include` and `exclude_*` must not all be empty.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Filter {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    include: Vec<ConversationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_external_shared_channels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_bot_users: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationType {
+    Im,
+    Mpim,
+    Private,
+    Public,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Filter::default()
+    }
+
+    pub fn include(mut self, include: Vec<ConversationType>) -> Self {
+        self.include = include;
+        self
+    }
+
+    pub fn exclude_external_shared_channels(mut self, exclude: bool) -> Self {
+        self.exclude_external_shared_channels = Some(exclude);
+        self
+    }
+
+    pub fn exclude_bot_users(mut self, exclude: bool) -> Self {
+        self.exclude_bot_users = Some(exclude);
+        self
+    }
+}
+
+impl Validate for Filter {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        if self.include.is_empty()
+            && self.exclude_external_shared_channels.is_none()
+            && self.exclude_bot_users.is_none()
+        {
+            violations.push(Violation {
+                path: path.to_string(),
+                kind: ViolationKind::Empty,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ser_all() {
+        let filter = Filter::new()
+            .include(vec![ConversationType::Public, ConversationType::Mpim])
+            .exclude_external_shared_channels(true)
+            .exclude_bot_users(false);
+        let json = serde_json::to_string_pretty(&filter).unwrap_or("".to_string());
+        let expected = r#"{
+  "include": [
+    "public",
+    "mpim"
+  ],
+  "exclude_external_shared_channels": true,
+  "exclude_bot_users": false
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let filter = Filter::new().include(vec![ConversationType::Im]);
+        let json = serde_json::to_string(&filter).unwrap();
+        let decoded = serde_json::from_str::<Filter>(&json).unwrap();
+        assert_eq!(decoded, filter);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Filter::new().exclude_bot_users(false).validate(), Ok(()));
+
+        let violations = Filter::new().validate().unwrap_err();
+        assert_eq!(
+            violations,
+            vec![Violation {
+                path: "".to_string(),
+                kind: ViolationKind::Empty,
+            }]
+        );
+    }
+}
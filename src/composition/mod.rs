@@ -1,4 +1,6 @@
 pub mod confirmation_dialog;
+pub mod dispatch_action_config;
+pub mod filter;
 pub mod option;
 pub mod option_group;
 pub mod text;
@@ -1,14 +1,19 @@
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{
+    check_length, check_optional_length, check_text, field, Validate, Violation,
+};
+use serde::{Deserialize, Serialize};
 
 /// An object that represents a single selectable item in a select menu,
 /// multi-select menu, radio button group, or overflow menu.
-#[derive(Debug, Default, Serialize, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OptionObject {
     text: Text,
     value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     url: Option<String>,
 }
 
@@ -20,6 +25,24 @@ impl OptionObject {
             ..OptionObject::default()
         }
     }
+
+    /// A line of descriptive text shown below the `text`,
+    /// used by checkboxes and radio buttons.
+    pub fn description(mut self, description: impl Into<PlainText>) -> Self {
+        self.description = Some(Plain(description.into()));
+        self
+    }
+}
+
+impl Validate for OptionObject {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "text"), &self.text, 75, violations);
+        check_length(field(path, "value"), &self.value, 150, violations);
+        if let Some(description) = &self.description {
+            check_text(&field(path, "description"), description, 75, violations);
+        }
+        check_optional_length(field(path, "url"), &self.url, 3000, violations);
+    }
 }
 
 #[cfg(test)]
@@ -39,4 +62,30 @@ mod test {
 }"#;
         assert_eq!(json, expected.to_string());
     }
+
+    #[test]
+    fn test_ser_description() {
+        let option = OptionObject::new("text", "value").description("description");
+        let json = serde_json::to_string_pretty(&option).unwrap_or("".to_string());
+        let expected = r#"{
+  "text": {
+    "type": "plain_text",
+    "text": "text"
+  },
+  "value": "value",
+  "description": {
+    "type": "plain_text",
+    "text": "description"
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let option = OptionObject::new(PlainText::new("text"), "value").description("description");
+        let json = serde_json::to_string(&option).unwrap();
+        let decoded = serde_json::from_str::<OptionObject>(&json).unwrap();
+        assert_eq!(decoded, option);
+    }
 }
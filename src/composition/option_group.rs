@@ -1,9 +1,10 @@
 use crate::composition::option::OptionObject;
 use crate::composition::text::Text::Plain;
 use crate::composition::text::{PlainText, Text};
-use serde::Serialize;
+use crate::validation::{check_count, check_text, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OptionGroup {
     label: Text,
     options: Vec<OptionObject>,
@@ -17,3 +18,12 @@ impl OptionGroup {
         }
     }
 }
+
+impl Validate for OptionGroup {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "label"), &self.label, 75, violations);
+        check_count(field(path, "options"), &self.options, 100, violations);
+        self.options
+            .collect_violations(&field(path, "options"), violations);
+    }
+}
@@ -1,32 +1,54 @@
-use crate::composition::text::Text::Plain;
-use serde::Serialize;
+use crate::composition::text::Text::{Markdown, Plain};
+use crate::de::tagged;
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::from_value;
+
+pub(crate) const PLAIN_TEXT: &str = "plain_text";
+pub(crate) const MARKDOWN: &str = "mrkdwn";
 
 /// An object containing some text, formatted either as `plain_text` or using `mrkdwn`,
 /// our proprietary textual markup that's just different enough from Markdown to frustrate you.
-
-const PLAIN_TEXT: &str = "plain_text";
-const MARKDOWN: &str = "mrkdwn";
-
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Text {
     Plain(PlainText),
     Markdown(MarkdownText),
 }
 
-#[derive(Debug, Serialize, Clone)]
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let text = match type_name.as_str() {
+            PLAIN_TEXT => from_value(value).map(Plain),
+            MARKDOWN => from_value(value).map(Markdown),
+            _ => {
+                return Err(D::Error::unknown_variant(
+                    &type_name,
+                    &[PLAIN_TEXT, MARKDOWN],
+                ))
+            }
+        };
+        text.map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlainText {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     emoji: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MarkdownText {
     #[serde(rename = "type")]
-    type_name: &'static str,
+    type_name: String,
     text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     verbatim: Option<bool>,
@@ -65,7 +87,7 @@ impl Default for Text {
 impl Default for PlainText {
     fn default() -> Self {
         PlainText {
-            type_name: PLAIN_TEXT,
+            type_name: PLAIN_TEXT.to_string(),
             text: String::default(),
             emoji: Option::default(),
         }
@@ -75,7 +97,7 @@ impl Default for PlainText {
 impl Default for MarkdownText {
     fn default() -> Self {
         MarkdownText {
-            type_name: MARKDOWN,
+            type_name: MARKDOWN.to_string(),
             text: String::default(),
             verbatim: Option::default(),
         }
@@ -110,6 +132,29 @@ impl MarkdownText {
     }
 }
 
+impl Text {
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Plain(plain) => &plain.text,
+            Markdown(markdown) => &markdown.text,
+        }
+    }
+}
+
+/// For `deserialize_with` of fields which only accept `plain_text`.
+pub(crate) fn deserialize_plain<'de, D>(deserializer: D) -> Result<Text, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Text::deserialize(deserializer)? {
+        Plain(plain) => Ok(Plain(plain)),
+        Markdown(_) => Err(D::Error::invalid_value(
+            Unexpected::Str(MARKDOWN),
+            &PLAIN_TEXT,
+        )),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::composition::text::{MarkdownText, PlainText, Text};
@@ -180,4 +225,21 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_de_text() {
+        let json = r#"{ "type": "plain_text", "text": "plain", "emoji": true }"#;
+        let text = serde_json::from_str::<Text>(json).unwrap();
+        assert_eq!(text, Text::Plain(PlainText::new("plain").emoji(true)));
+
+        let json = r#"{ "type": "mrkdwn", "text": "*markdown*", "verbatim": true }"#;
+        let text = serde_json::from_str::<Text>(json).unwrap();
+        assert_eq!(
+            text,
+            Text::Markdown(MarkdownText::new("*markdown*").verbatim(true))
+        );
+
+        let json = r#"{ "type": "html", "text": "<b>html</b>" }"#;
+        assert!(serde_json::from_str::<Text>(json).is_err());
+    }
 }
@@ -6,4 +6,7 @@ pub struct SlackConfig {
     pub bot_token: String,
     #[serde(rename = "slack_channel")]
     pub channel: String,
+    /// Used to verify requests from Slack by `SignatureVerifier`.
+    #[serde(rename = "slack_signing_secret")]
+    pub signing_secret: Option<String>,
 }
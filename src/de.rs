@@ -0,0 +1,18 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Buffers an object and reads its `type`, so that the variant of untagged enums like
+/// `Block` can be chosen before the object itself is deserialized.
+pub(crate) fn tagged<'de, D>(deserializer: D) -> Result<(String, Value), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    let type_name = value
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| D::Error::missing_field("type"))?
+        .to_string();
+    Ok((type_name, value))
+}
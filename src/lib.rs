@@ -2,6 +2,12 @@ pub mod api;
 pub mod attachment;
 pub mod block;
 pub mod block_element;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod composition;
 pub mod config;
+mod de;
 pub mod payload;
+pub mod signature;
+pub mod validation;
+pub mod view;
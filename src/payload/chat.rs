@@ -1,4 +1,5 @@
 use crate::payload::message::CommonMessagePayload;
+use crate::validation::{Validate, Violation};
 use serde::Serialize;
 
 /// Reference.
@@ -79,6 +80,12 @@ impl ChatPostMessagePayload {
     }
 }
 
+impl Validate for ChatPostMessagePayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.payload.collect_violations(path, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
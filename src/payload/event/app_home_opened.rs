@@ -0,0 +1,64 @@
+use crate::payload::view::View;
+use serde::Deserialize;
+
+/// Sent when a user clicks into the App Home.
+/// `view` is the Home tab published before, if any.
+///
+/// Reference:
+/// https://api.slack.com/events/app_home_opened
+#[derive(Debug, Deserialize)]
+pub struct AppHomeOpenedEvent {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: String,
+    pub channel: String,
+    /// `home` or `messages`.
+    pub tab: String,
+    pub event_ts: String,
+    pub view: Option<View>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_event() {
+        let json = r#"{
+  "type": "app_home_opened",
+  "user": "U123",
+  "channel": "D123",
+  "event_ts": "1515449522000016",
+  "tab": "home",
+  "view": {
+    "id": "V123",
+    "team_id": "T123",
+    "type": "home",
+    "blocks": [],
+    "private_metadata": "",
+    "callback_id": "",
+    "hash": "1231232323.12321312",
+    "app_id": "A123",
+    "bot_id": "B123"
+  }
+}"#;
+        let event = serde_json::from_str::<AppHomeOpenedEvent>(json).unwrap();
+        assert_eq!(event.type_name, "app_home_opened");
+        assert_eq!(event.user, "U123");
+        assert_eq!(event.tab, "home");
+        assert_eq!(event.view.unwrap().id, "V123");
+    }
+
+    #[test]
+    fn test_de_first_open() {
+        let json = r#"{
+  "type": "app_home_opened",
+  "user": "U123",
+  "channel": "D123",
+  "event_ts": "1515449522000016",
+  "tab": "messages"
+}"#;
+        let event = serde_json::from_str::<AppHomeOpenedEvent>(json).unwrap();
+        assert!(event.view.is_none());
+    }
+}
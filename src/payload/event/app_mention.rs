@@ -0,0 +1,42 @@
+use crate::block::Block;
+use serde::Deserialize;
+
+/// Sent when the app is mentioned in a message.
+///
+/// Reference:
+/// https://api.slack.com/events/app_mention
+#[derive(Debug, Deserialize)]
+pub struct AppMentionEvent {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: String,
+    pub text: String,
+    pub ts: String,
+    pub channel: String,
+    pub event_ts: String,
+    pub thread_ts: Option<String>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_event() {
+        let json = r#"{
+  "type": "app_mention",
+  "user": "U061F7AUR",
+  "text": "<@U0LAN0Z89> is it everything a river should be?",
+  "ts": "1515449522.000016",
+  "channel": "C0LAN2Q65",
+  "event_ts": "1515449522000016"
+}"#;
+        let event = serde_json::from_str::<AppMentionEvent>(json).unwrap();
+        assert_eq!(event.user, "U061F7AUR");
+        assert_eq!(event.channel, "C0LAN2Q65");
+        assert_eq!(event.thread_ts, None);
+        assert!(event.blocks.is_empty());
+    }
+}
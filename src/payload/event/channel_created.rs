@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+/// Sent when a public channel is created.
+///
+/// Reference:
+/// https://api.slack.com/events/channel_created
+#[derive(Debug, Deserialize)]
+pub struct ChannelCreatedEvent {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub channel: CreatedChannel,
+    pub event_ts: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatedChannel {
+    pub id: String,
+    pub name: String,
+    /// Unix timestamp in seconds.
+    pub created: i64,
+    pub creator: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_event() {
+        let json = r#"{
+  "type": "channel_created",
+  "channel": {
+    "id": "C024BE91L",
+    "name": "fun",
+    "created": 1360782804,
+    "creator": "U024BE7LH"
+  }
+}"#;
+        let event = serde_json::from_str::<ChannelCreatedEvent>(json).unwrap();
+        assert_eq!(event.channel.name, "fun");
+        assert_eq!(event.channel.created, 1360782804);
+    }
+}
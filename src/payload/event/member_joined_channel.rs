@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+/// Sent when a user joins a channel the app is in.
+///
+/// Reference:
+/// https://api.slack.com/events/member_joined_channel
+#[derive(Debug, Deserialize)]
+pub struct MemberJoinedChannelEvent {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: String,
+    pub channel: String,
+    /// `C` for public channels and `G` for private channels.
+    pub channel_type: String,
+    pub team: String,
+    /// Not included when the user joined by themselves.
+    pub inviter: Option<String>,
+    pub event_ts: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_event() {
+        let json = r#"{
+  "type": "member_joined_channel",
+  "user": "W06GH7XHN",
+  "channel": "C0698JE0H",
+  "channel_type": "C",
+  "team": "T024BE7LD",
+  "inviter": "U123456789"
+}"#;
+        let event = serde_json::from_str::<MemberJoinedChannelEvent>(json).unwrap();
+        assert_eq!(event.user, "W06GH7XHN");
+        assert_eq!(event.channel_type, "C");
+        assert_eq!(event.inviter, Some("U123456789".to_string()));
+    }
+}
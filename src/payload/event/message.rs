@@ -0,0 +1,234 @@
+use crate::block::Block;
+use crate::payload::message::Message;
+use serde::Deserialize;
+
+/// Sent when a message is posted to a channel the app is in.
+/// Changes of messages are also sent as a message with `subtype`.
+///
+/// Reference:
+/// https://api.slack.com/events/message
+#[derive(Debug, Deserialize)]
+pub struct MessageEvent {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// `None` for messages posted by users.
+    pub subtype: Option<MessageSubtype>,
+    pub channel: String,
+    /// `channel`, `group`, `im` or `mpim`.
+    pub channel_type: Option<String>,
+    pub user: Option<String>,
+    pub bot_id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    pub ts: Option<String>,
+    pub thread_ts: Option<String>,
+    pub event_ts: String,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    /// Whether the message is hidden from the channel, like `message_changed`.
+    #[serde(default)]
+    pub hidden: bool,
+    /// The new message of `message_changed`.
+    pub message: Option<Message>,
+    /// The original message of `message_changed` and `message_deleted`.
+    pub previous_message: Option<Message>,
+    /// The timestamp of the message removed by `message_deleted`.
+    pub deleted_ts: Option<String>,
+}
+
+/// Reference:
+/// https://api.slack.com/events/message#subtypes
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageSubtype {
+    BotMessage,
+    MeMessage,
+    MessageChanged,
+    MessageDeleted,
+    MessageReplied,
+    ThreadBroadcast,
+    ChannelJoin,
+    ChannelLeave,
+    ChannelTopic,
+    ChannelPurpose,
+    ChannelName,
+    FileShare,
+    /// Subtypes which are not supported by this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::rich_text::{RichTextElement, RichTextInlineElement};
+
+    #[test]
+    fn test_de_event() {
+        let json = r#"{
+  "type": "message",
+  "channel": "C2147483705",
+  "user": "U2147483697",
+  "text": "Hello world",
+  "ts": "1355517523.000005",
+  "event_ts": "1355517523.000005",
+  "channel_type": "channel"
+}"#;
+        let event = serde_json::from_str::<MessageEvent>(json).unwrap();
+        assert_eq!(event.subtype, None);
+        assert_eq!(event.user, Some("U2147483697".to_string()));
+        assert_eq!(event.text, "Hello world");
+        assert_eq!(event.channel_type, Some("channel".to_string()));
+    }
+
+    #[test]
+    fn test_de_subtypes() {
+        let json = r#"{
+  "type": "message",
+  "subtype": "message_changed",
+  "hidden": true,
+  "channel": "C123ABC456",
+  "ts": "1358878755.000001",
+  "event_ts": "1358878755.000001",
+  "message": {
+    "type": "message",
+    "user": "U123ABC456",
+    "text": "Hello, world!",
+    "ts": "1355517523.000005",
+    "edited": {
+      "user": "U123ABC456",
+      "ts": "1358878755.000001"
+    }
+  },
+  "previous_message": {
+    "type": "message",
+    "user": "U123ABC456",
+    "text": "Hello world",
+    "ts": "1355517523.000005"
+  }
+}"#;
+        let event = serde_json::from_str::<MessageEvent>(json).unwrap();
+        assert_eq!(event.subtype, Some(MessageSubtype::MessageChanged));
+        assert!(event.hidden);
+        assert_eq!(event.message.unwrap().text, "Hello, world!");
+        assert_eq!(event.previous_message.unwrap().text, "Hello world");
+
+        let json = r#"{
+  "type": "message",
+  "subtype": "message_deleted",
+  "hidden": true,
+  "channel": "C123ABC456",
+  "ts": "1358878755.000001",
+  "deleted_ts": "1358878749.000002",
+  "event_ts": "1358878755.000001"
+}"#;
+        let event = serde_json::from_str::<MessageEvent>(json).unwrap();
+        assert_eq!(event.subtype, Some(MessageSubtype::MessageDeleted));
+        assert_eq!(event.deleted_ts, Some("1358878749.000002".to_string()));
+
+        let json = r#"{
+  "type": "message",
+  "subtype": "huddle_thread",
+  "channel": "C123ABC456",
+  "ts": "1358878755.000001",
+  "event_ts": "1358878755.000001"
+}"#;
+        let event = serde_json::from_str::<MessageEvent>(json).unwrap();
+        assert_eq!(event.subtype, Some(MessageSubtype::Unknown));
+    }
+
+    #[test]
+    fn test_de_unknown_blocks() {
+        let json = r#"{
+  "type": "message",
+  "subtype": "bot_message",
+  "channel": "C123ABC456",
+  "bot_id": "B123ABC456",
+  "text": "Huddle started",
+  "ts": "1355517523.000005",
+  "event_ts": "1355517523.000005",
+  "blocks": [
+    {
+      "type": "call",
+      "block_id": "call",
+      "call_id": "R123ABC456"
+    },
+    {
+      "type": "section",
+      "fields": [
+        {
+          "type": "mrkdwn",
+          "text": "*Room*"
+        }
+      ]
+    }
+  ],
+  "message": {
+    "type": "message",
+    "text": "Huddle started",
+    "ts": "1355517523.000005",
+    "blocks": [
+      {
+        "type": "call",
+        "block_id": "call",
+        "call_id": "R123ABC456"
+      }
+    ]
+  }
+}"#;
+        let event = serde_json::from_str::<MessageEvent>(json).unwrap();
+        assert_eq!(event.blocks.len(), 2);
+        match &event.blocks[0] {
+            Block::Unknown(value) => assert_eq!(value["call_id"], "R123ABC456"),
+            block => panic!("unexpected block: {:?}", block),
+        }
+        match &event.message.unwrap().blocks[0] {
+            Block::Unknown(value) => assert_eq!(value["type"], "call"),
+            block => panic!("unexpected block: {:?}", block),
+        }
+    }
+
+    #[test]
+    fn test_de_rich_text_unknown_elements() {
+        let json = r##"{
+  "type": "message",
+  "channel": "C123ABC456",
+  "user": "U123ABC456",
+  "text": "#F405B3 from <!subteam^S1>",
+  "ts": "1355517523.000005",
+  "event_ts": "1355517523.000005",
+  "blocks": [
+    {
+      "type": "rich_text",
+      "block_id": "Xy1",
+      "elements": [
+        {
+          "type": "rich_text_section",
+          "elements": [
+            { "type": "color", "value": "#F405B3" },
+            { "type": "text", "text": " from " },
+            { "type": "team", "team_id": "T123ABC456" }
+          ]
+        }
+      ]
+    }
+  ]
+}"##;
+        let event = serde_json::from_str::<MessageEvent>(json).unwrap();
+        match &event.blocks[0] {
+            Block::RichText(rich_text) => match &rich_text.elements()[0] {
+                RichTextElement::Section(section) => {
+                    assert_eq!(section.elements().len(), 3);
+                    match &section.elements()[2] {
+                        RichTextInlineElement::Unknown(value) => {
+                            assert_eq!(value["team_id"], "T123ABC456")
+                        }
+                        element => panic!("unexpected element: {:?}", element),
+                    }
+                }
+                element => panic!("unexpected element: {:?}", element),
+            },
+            block => panic!("unexpected block: {:?}", block),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::de::tagged;
+use crate::payload::event::app_home_opened::AppHomeOpenedEvent;
+use crate::payload::event::app_mention::AppMentionEvent;
+use crate::payload::event::channel_created::ChannelCreatedEvent;
+use crate::payload::event::member_joined_channel::MemberJoinedChannelEvent;
+use crate::payload::event::message::MessageEvent;
+use crate::payload::event::reaction::ReactionEvent;
+use crate::payload::event::Event::{
+    AppHomeOpened, AppMention, ChannelCreated, MemberJoinedChannel, Message, ReactionAdded,
+    ReactionRemoved,
+};
+use crate::payload::event::EventPayload::{EventCallback, UrlVerification};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use serde_json::{from_value, Value};
+
+pub mod app_home_opened;
+pub mod app_mention;
+pub mod channel_created;
+pub mod member_joined_channel;
+pub mod message;
+pub mod reaction;
+
+const URL_VERIFICATION_TYPE: &str = "url_verification";
+const EVENT_CALLBACK_TYPE: &str = "event_callback";
+
+const MESSAGE_TYPE: &str = "message";
+const APP_MENTION_TYPE: &str = "app_mention";
+const REACTION_ADDED_TYPE: &str = "reaction_added";
+const REACTION_REMOVED_TYPE: &str = "reaction_removed";
+const MEMBER_JOINED_CHANNEL_TYPE: &str = "member_joined_channel";
+const CHANNEL_CREATED_TYPE: &str = "channel_created";
+const APP_HOME_OPENED_TYPE: &str = "app_home_opened";
+
+/// Any request body sent to the request URL of the Events API.
+///
+/// Payloads of unknown `type`, like `app_rate_limited`, are kept as `Other` instead of failing.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::payload::event::EventPayload;
+///
+/// let json = r#"{ "type": "url_verification", "token": "token", "challenge": "challenge" }"#;
+/// match serde_json::from_str::<EventPayload>(json).unwrap() {
+///     // Respond with the challenge to verify the request URL.
+///     EventPayload::UrlVerification(payload) => assert_eq!(payload.challenge, "challenge"),
+///     _ => panic!(),
+/// }
+/// ```
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum EventPayload {
+    UrlVerification(UrlVerificationPayload),
+    EventCallback(EventCallbackPayload),
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for EventPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let payload = match type_name.as_str() {
+            URL_VERIFICATION_TYPE => from_value(value).map(UrlVerification),
+            EVENT_CALLBACK_TYPE => from_value(value).map(EventCallback),
+            _ => Ok(EventPayload::Other(value)),
+        };
+        payload.map_err(D::Error::custom)
+    }
+}
+
+/// Sent once when the request URL is configured.
+///
+/// Reference:
+/// https://api.slack.com/events/url_verification
+#[derive(Debug, Deserialize)]
+pub struct UrlVerificationPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub token: String,
+    pub challenge: String,
+}
+
+/// The envelope of every event delivered to the app.
+///
+/// Reference:
+/// https://api.slack.com/apis/connections/events-api#callback-field
+#[derive(Debug, Deserialize)]
+pub struct EventCallbackPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub team_id: String,
+    pub api_app_id: String,
+    pub event: Event,
+    /// Unique across all workspaces, useful to ignore retried deliveries.
+    pub event_id: String,
+    /// Unix timestamp in seconds.
+    pub event_time: i64,
+    /// Installations of the app which can see this event.
+    #[serde(default)]
+    pub authorizations: Vec<Authorization>,
+    pub event_context: Option<String>,
+    #[serde(default)]
+    pub is_ext_shared_channel: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Authorization {
+    pub enterprise_id: Option<String>,
+    pub team_id: Option<String>,
+    pub user_id: String,
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// The inner `event` of `EventCallbackPayload`, by its `type`.
+///
+/// Events which are not supported by this crate are kept as `Other`.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum Event {
+    Message(MessageEvent),
+    AppMention(AppMentionEvent),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    MemberJoinedChannel(MemberJoinedChannelEvent),
+    ChannelCreated(ChannelCreatedEvent),
+    AppHomeOpened(AppHomeOpenedEvent),
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let event = match type_name.as_str() {
+            MESSAGE_TYPE => from_value(value).map(Message),
+            APP_MENTION_TYPE => from_value(value).map(AppMention),
+            REACTION_ADDED_TYPE => from_value(value).map(ReactionAdded),
+            REACTION_REMOVED_TYPE => from_value(value).map(ReactionRemoved),
+            MEMBER_JOINED_CHANNEL_TYPE => from_value(value).map(MemberJoinedChannel),
+            CHANNEL_CREATED_TYPE => from_value(value).map(ChannelCreated),
+            APP_HOME_OPENED_TYPE => from_value(value).map(AppHomeOpened),
+            _ => Ok(Event::Other(value)),
+        };
+        event.map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_url_verification() {
+        let json = r#"{
+  "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+  "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+  "type": "url_verification"
+}"#;
+        match serde_json::from_str::<EventPayload>(json).unwrap() {
+            UrlVerification(payload) => assert_eq!(
+                payload.challenge,
+                "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+            ),
+            payload => panic!("unexpected payload: {:?}", payload),
+        }
+    }
+
+    #[test]
+    fn test_de_event_callback() {
+        let json = r#"{
+  "token": "XXYYZZ",
+  "team_id": "T123ABC456",
+  "api_app_id": "A123ABC456",
+  "event": {
+    "type": "reaction_removed",
+    "user": "U123ABC456",
+    "reaction": "thumbsup",
+    "item_user": "U222222222",
+    "item": {
+      "type": "message",
+      "channel": "C123ABC456",
+      "ts": "1360782400.498405"
+    },
+    "event_ts": "1360782804.083113"
+  },
+  "type": "event_callback",
+  "authorizations": [
+    {
+      "enterprise_id": null,
+      "team_id": "T123ABC456",
+      "user_id": "U123ABC456",
+      "is_bot": true,
+      "is_enterprise_install": false
+    }
+  ],
+  "event_context": "EC123ABC456",
+  "event_id": "Ev123ABC456",
+  "event_time": 1234567890
+}"#;
+        let payload = match serde_json::from_str::<EventPayload>(json).unwrap() {
+            EventCallback(payload) => payload,
+            payload => panic!("unexpected payload: {:?}", payload),
+        };
+        assert_eq!(payload.team_id, "T123ABC456");
+        assert_eq!(payload.api_app_id, "A123ABC456");
+        assert_eq!(payload.event_id, "Ev123ABC456");
+        assert_eq!(payload.event_time, 1234567890);
+        assert_eq!(payload.authorizations[0].user_id, "U123ABC456");
+        assert!(payload.authorizations[0].is_bot);
+        match payload.event {
+            ReactionRemoved(event) => assert_eq!(event.reaction, "thumbsup"),
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_de_unknown() {
+        let json = r#"{
+  "type": "pin_added",
+  "user": "U123ABC456",
+  "channel_id": "C123ABC456",
+  "event_ts": "1360782804.083113"
+}"#;
+        match serde_json::from_str::<Event>(json).unwrap() {
+            Event::Other(value) => assert_eq!(value["channel_id"], "C123ABC456"),
+            event => panic!("unexpected event: {:?}", event),
+        }
+
+        let json = r#"{
+  "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+  "type": "app_rate_limited",
+  "team_id": "T123456",
+  "minute_rate_limited": 1518467820,
+  "api_app_id": "A123456"
+}"#;
+        match serde_json::from_str::<EventPayload>(json).unwrap() {
+            EventPayload::Other(value) => assert_eq!(value["team_id"], "T123456"),
+            payload => panic!("unexpected payload: {:?}", payload),
+        }
+
+        let json = r#"{ "type": "message", "text": "no channel" }"#;
+        let error = serde_json::from_str::<Event>(json).unwrap_err();
+        assert!(error.to_string().starts_with("missing field `channel`"));
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+/// Sent as `reaction_added` or `reaction_removed`, by `type_name`.
+///
+/// Reference:
+/// https://api.slack.com/events/reaction_added
+#[derive(Debug, Deserialize)]
+pub struct ReactionEvent {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: String,
+    /// Name of the emoji, without colons.
+    pub reaction: String,
+    /// The author of the reacted item.
+    pub item_user: Option<String>,
+    pub item: ReactionItem,
+    pub event_ts: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactionItem {
+    Message {
+        channel: String,
+        ts: String,
+    },
+    File {
+        file: String,
+    },
+    FileComment {
+        file: String,
+        file_comment: String,
+    },
+    /// Items which are not supported by this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_event() {
+        let json = r#"{
+  "type": "reaction_added",
+  "user": "U024BE7LH",
+  "reaction": "thumbsup",
+  "item_user": "U0G9QF9C6",
+  "item": {
+    "type": "message",
+    "channel": "C0G9QF9GZ",
+    "ts": "1360782400.498405"
+  },
+  "event_ts": "1360782804.083113"
+}"#;
+        let event = serde_json::from_str::<ReactionEvent>(json).unwrap();
+        assert_eq!(event.reaction, "thumbsup");
+        match event.item {
+            ReactionItem::Message { channel, ts } => {
+                assert_eq!(channel, "C0G9QF9GZ");
+                assert_eq!(ts, "1360782400.498405");
+            }
+            item => panic!("unexpected item: {:?}", item),
+        }
+    }
+}
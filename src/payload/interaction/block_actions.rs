@@ -1,3 +1,6 @@
+use crate::block_element::date_time::{Date, Time};
+use crate::payload::message::Message;
+use crate::payload::view::{View, ViewState};
 use serde::Deserialize;
 
 /// Received when a user interacts with a Block Kit interactive component.
@@ -10,10 +13,45 @@ pub struct BlockActionsPayload {
     pub type_name: String,
     pub trigger_id: String,
     pub user: User,
-    pub response_url: String,
+    /// `null` for org-wide installations.
+    pub team: Option<Team>,
+    pub api_app_id: Option<String>,
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    /// Where the interacted element is placed.
+    pub container: Option<Container>,
+    /// Not included when the interaction happened in a view.
+    pub channel: Option<Channel>,
+    /// The message containing the element, when `container` is a message.
+    pub message: Option<Message>,
+    /// The view containing the element, when `container` is a view.
+    pub view: Option<View>,
+    /// Values of the interactive elements in the message.
+    pub state: Option<ViewState>,
+    pub hash: Option<String>,
+    /// Not included when the interaction happened in a view, like the Home tab.
+    pub response_url: Option<String>,
     pub actions: Vec<Actions>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container {
+    Message {
+        message_ts: String,
+        channel_id: String,
+        #[serde(default)]
+        is_ephemeral: bool,
+    },
+    View {
+        view_id: String,
+    },
+    /// Containers which are not supported by this crate, like `message_attachment`.
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Actions {
     #[serde(rename = "type")]
@@ -23,7 +61,18 @@ pub struct Actions {
     pub text: Option<Text>,
     pub value: Option<String>,
     pub selected_option: Option<SelectedOption>,
-    pub selected_date: Option<String>,
+    pub selected_options: Option<Vec<SelectedOption>>,
+    pub selected_date: Option<Date>,
+    pub selected_time: Option<Time>,
+    /// Unix timestamp in seconds of `datetimepicker`.
+    pub selected_date_time: Option<i64>,
+    pub selected_user: Option<String>,
+    pub selected_users: Option<Vec<String>>,
+    pub selected_conversation: Option<String>,
+    pub selected_conversations: Option<Vec<String>>,
+    pub selected_channel: Option<String>,
+    pub selected_channels: Option<Vec<String>>,
+    pub action_ts: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -31,25 +80,49 @@ pub struct Text {
     #[serde(rename = "type")]
     pub type_name: String,
     pub text: String,
-    pub emoji: bool,
+    pub emoji: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct User {
     pub id: String,
     pub username: String,
+    pub name: Option<String>,
     pub team_id: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Team {
+    pub id: String,
+    pub domain: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Enterprise {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Channel {
+    pub id: String,
+    pub name: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SelectedOption {
     pub text: Text,
     pub value: String,
+    pub description: Option<Text>,
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::block::section::SectionBlock;
+    use crate::block::Block;
+    use crate::composition::text::Text::{Markdown, Plain};
+    use crate::composition::text::{MarkdownText, PlainText};
 
     #[test]
     fn test_de_payload() {
@@ -92,7 +165,10 @@ mod test {
         assert_eq!(payload.user.username, "name");
         assert_eq!(payload.user.team_id, "team_id");
 
-        assert_eq!(payload.response_url, "https://example.com");
+        assert_eq!(
+            payload.response_url,
+            Some("https://example.com".to_string())
+        );
 
         assert_eq!(payload.actions[0].action_id, "action_id");
         assert_eq!(payload.actions[0].block_id, "block_id");
@@ -104,4 +180,507 @@ mod test {
             "selected_value"
         );
     }
+
+    #[test]
+    fn test_de_selected_conversations() {
+        let json = r#"{
+  "type": "block_actions",
+  "trigger_id": "trigger_id",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "response_url": "https://example.com",
+  "actions": [
+    {
+      "type": "users_select",
+      "action_id": "user",
+      "block_id": "block_id",
+      "selected_user": "U123"
+    },
+    {
+      "type": "multi_conversations_select",
+      "action_id": "conversations",
+      "block_id": "block_id",
+      "selected_conversations": ["C123", "D456"]
+    },
+    {
+      "type": "channels_select",
+      "action_id": "channel",
+      "block_id": "block_id",
+      "selected_channel": "C789"
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        assert_eq!(payload.actions[0].selected_user, Some("U123".to_string()));
+        assert_eq!(
+            payload.actions[1].selected_conversations,
+            Some(vec!["C123".to_string(), "D456".to_string()])
+        );
+        assert_eq!(
+            payload.actions[2].selected_channel,
+            Some("C789".to_string())
+        );
+        assert_eq!(payload.actions[2].selected_channels, None);
+    }
+
+    #[test]
+    fn test_de_selected_options() {
+        let json = r#"{
+  "type": "block_actions",
+  "trigger_id": "trigger_id",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "response_url": "https://example.com",
+  "actions": [
+    {
+      "type": "checkboxes",
+      "action_id": "action_id",
+      "block_id": "block_id",
+      "selected_options": [
+        {
+          "text": {
+            "type": "plain_text",
+            "text": "text1",
+            "emoji": true
+          },
+          "value": "value1"
+        },
+        {
+          "text": {
+            "type": "plain_text",
+            "text": "text2",
+            "emoji": true
+          },
+          "value": "value2"
+        }
+      ]
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        let values = payload.actions[0]
+            .selected_options
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|option| option.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec!["value1", "value2"]);
+    }
+
+    #[test]
+    fn test_de_selected_time() {
+        let json = r#"{
+  "type": "block_actions",
+  "trigger_id": "trigger_id",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "response_url": "https://example.com",
+  "actions": [
+    {
+      "type": "timepicker",
+      "action_id": "time",
+      "block_id": "block_id",
+      "selected_time": "09:30"
+    },
+    {
+      "type": "datetimepicker",
+      "action_id": "date_time",
+      "block_id": "block_id",
+      "selected_date_time": 1628633820
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        assert_eq!(
+            payload.actions[0].selected_time,
+            Some(Time::from_hm(9, 30).unwrap())
+        );
+        assert_eq!(payload.actions[1].selected_date_time, Some(1628633820));
+    }
+
+    #[test]
+    fn test_de_home_tab() {
+        let json = r#"{
+  "type": "block_actions",
+  "trigger_id": "trigger_id",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "view": {
+    "id": "V123",
+    "team_id": "team_id",
+    "type": "home",
+    "blocks": [
+      {
+        "type": "actions",
+        "block_id": "block_id",
+        "elements": [
+          {
+            "type": "button",
+            "action_id": "refresh",
+            "text": {
+              "type": "plain_text",
+              "text": "Refresh",
+              "emoji": true
+            }
+          }
+        ]
+      }
+    ],
+    "private_metadata": "",
+    "callback_id": "",
+    "hash": "1605664221.a5fd3aa8"
+  },
+  "actions": [
+    {
+      "type": "button",
+      "action_id": "refresh",
+      "block_id": "block_id",
+      "text": {
+        "type": "plain_text",
+        "text": "Refresh",
+        "emoji": true
+      }
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        assert_eq!(payload.response_url, None);
+
+        let view = payload.view.unwrap();
+        assert_eq!(view.type_name, "home");
+        assert_eq!(view.hash, Some("1605664221.a5fd3aa8".to_string()));
+    }
+
+    #[test]
+    fn test_de_message_fixture() {
+        let json = r#"{
+  "type": "block_actions",
+  "user": {
+    "id": "U01ABCDEF12",
+    "username": "amy.mcgee",
+    "name": "amy.mcgee",
+    "team_id": "T01ABCDEF34"
+  },
+  "api_app_id": "A01ABCDEF56",
+  "token": "Shh_its_a_seekrit",
+  "container": {
+    "type": "message",
+    "message_ts": "1611729352.000200",
+    "channel_id": "C01ABCDEF78",
+    "is_ephemeral": false
+  },
+  "trigger_id": "1677838386912.1661519214434.cd40a46f4b3e8a6a2d4c7d1f5e4a1b2c",
+  "team": {
+    "id": "T01ABCDEF34",
+    "domain": "example"
+  },
+  "enterprise": null,
+  "is_enterprise_install": false,
+  "channel": {
+    "id": "C01ABCDEF78",
+    "name": "general"
+  },
+  "message": {
+    "bot_id": "B01ABCDEF90",
+    "type": "message",
+    "text": "This content can't be displayed.",
+    "user": "U01ABCDEF00",
+    "ts": "1611729352.000200",
+    "team": "T01ABCDEF34",
+    "blocks": [
+      {
+        "type": "section",
+        "block_id": "Zl1",
+        "text": {
+          "type": "mrkdwn",
+          "text": "What do you want to do?",
+          "verbatim": false
+        },
+        "accessory": {
+          "type": "static_select",
+          "action_id": "tasks",
+          "placeholder": {
+            "type": "plain_text",
+            "text": "Tasks",
+            "emoji": true
+          },
+          "options": [
+            {
+              "text": {
+                "type": "plain_text",
+                "text": "Run test",
+                "emoji": true
+              },
+              "value": "test"
+            },
+            {
+              "text": {
+                "type": "plain_text",
+                "text": "Deploy",
+                "emoji": true
+              },
+              "value": "deploy"
+            }
+          ]
+        }
+      }
+    ]
+  },
+  "state": {
+    "values": {
+      "Zl1": {
+        "tasks": {
+          "type": "static_select",
+          "selected_option": {
+            "text": {
+              "type": "plain_text",
+              "text": "Deploy",
+              "emoji": true
+            },
+            "value": "deploy"
+          }
+        }
+      }
+    }
+  },
+  "response_url": "https://hooks.slack.com/actions/T01ABCDEF34/1677838386912/XyZ123",
+  "actions": [
+    {
+      "type": "static_select",
+      "action_id": "tasks",
+      "block_id": "Zl1",
+      "selected_option": {
+        "text": {
+          "type": "plain_text",
+          "text": "Deploy",
+          "emoji": true
+        },
+        "value": "deploy"
+      },
+      "placeholder": {
+        "type": "plain_text",
+        "text": "Tasks",
+        "emoji": true
+      },
+      "action_ts": "1611729365.482371"
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        assert_eq!(payload.api_app_id, Some("A01ABCDEF56".to_string()));
+        assert_eq!(payload.team.unwrap().domain, "example");
+        assert!(payload.enterprise.is_none());
+        assert!(!payload.is_enterprise_install);
+        assert_eq!(payload.channel.unwrap().name, "general");
+        assert_eq!(payload.user.name, Some("amy.mcgee".to_string()));
+        match payload.container.unwrap() {
+            Container::Message {
+                message_ts,
+                channel_id,
+                is_ephemeral,
+            } => {
+                assert_eq!(message_ts, "1611729352.000200");
+                assert_eq!(channel_id, "C01ABCDEF78");
+                assert!(!is_ephemeral);
+            }
+            container => panic!("unexpected container: {:?}", container),
+        }
+
+        let message = payload.message.unwrap();
+        assert_eq!(message.bot_id, Some("B01ABCDEF90".to_string()));
+        assert_eq!(message.blocks.len(), 1);
+
+        let values = &payload.state.unwrap().values;
+        assert!(values["Zl1"].contains_key("tasks"));
+
+        assert_eq!(
+            payload.actions[0].action_ts,
+            Some("1611729365.482371".to_string())
+        );
+    }
+
+    #[test]
+    fn test_de_message_with_lenient_blocks() {
+        let json = r#"{
+  "type": "block_actions",
+  "trigger_id": "trigger_id",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "container": {
+    "type": "message",
+    "message_ts": "1611729352.000200",
+    "channel_id": "C01ABCDEF78",
+    "is_ephemeral": false
+  },
+  "message": {
+    "type": "message",
+    "text": "fallback",
+    "ts": "1611729352.000200",
+    "blocks": [
+      {
+        "type": "section",
+        "block_id": "fields",
+        "fields": [
+          {
+            "type": "mrkdwn",
+            "text": "*Status*"
+          },
+          {
+            "type": "plain_text",
+            "text": "Open"
+          }
+        ]
+      },
+      {
+        "type": "call",
+        "block_id": "call",
+        "call_id": "R01ABCDEF12",
+        "api_decoration_available": false
+      },
+      {
+        "type": "actions",
+        "block_id": "actions",
+        "elements": [
+          {
+            "type": "button",
+            "action_id": "close",
+            "text": {
+              "type": "plain_text",
+              "text": "Close"
+            },
+            "value": "close"
+          },
+          {
+            "type": "workflow_button",
+            "action_id": "workflow",
+            "text": {
+              "type": "plain_text",
+              "text": "Run"
+            }
+          }
+        ]
+      }
+    ]
+  },
+  "actions": [
+    {
+      "type": "button",
+      "action_id": "close",
+      "block_id": "actions",
+      "value": "close",
+      "action_ts": "1611729365.482371"
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        assert_eq!(payload.actions[0].action_id, "close");
+
+        let blocks = payload.message.unwrap().blocks;
+        assert_eq!(blocks.len(), 3);
+        match &blocks[0] {
+            Block::Section(section) => assert_eq!(
+                section,
+                &SectionBlock::from_fields(vec![
+                    Markdown(MarkdownText::new("*Status*")),
+                    Plain(PlainText::new("Open")),
+                ])
+                .block_id("fields")
+            ),
+            block => panic!("unexpected block: {:?}", block),
+        }
+        match &blocks[1] {
+            Block::Unknown(value) => assert_eq!(value["call_id"], "R01ABCDEF12"),
+            block => panic!("unexpected block: {:?}", block),
+        }
+    }
+
+    #[test]
+    fn test_de_view_fixture() {
+        let json = r#"{
+  "type": "block_actions",
+  "team": {
+    "id": "T9TK3CUKW",
+    "domain": "example"
+  },
+  "user": {
+    "id": "UA8RXUSPL",
+    "username": "jtorrance",
+    "name": "jtorrance",
+    "team_id": "T9TK3CUKW"
+  },
+  "api_app_id": "AABA1ABCD",
+  "token": "9s8d9as89d8as9d8as989",
+  "container": {
+    "type": "view",
+    "view_id": "V0PKB1ZFV"
+  },
+  "trigger_id": "24571818370.22717085937.b2f8a0d9e1c7ef7c8a5bbd5e5c4f7f1a",
+  "enterprise": {
+    "id": "E0A1B2C3D",
+    "name": "Example Org"
+  },
+  "is_enterprise_install": true,
+  "view": {
+    "id": "V0PKB1ZFV",
+    "team_id": "T9TK3CUKW",
+    "type": "modal",
+    "blocks": [],
+    "private_metadata": "",
+    "callback_id": "new_ticket",
+    "state": {
+      "values": {}
+    },
+    "hash": "1605664221.a5fd3aa8",
+    "root_view_id": "V0PKB1ZFV",
+    "app_id": "AABA1ABCD",
+    "bot_id": "BA13894H"
+  },
+  "actions": [
+    {
+      "type": "button",
+      "action_id": "approve",
+      "block_id": "actions",
+      "text": {
+        "type": "plain_text",
+        "text": "Approve"
+      },
+      "value": "approve",
+      "action_ts": "1548426417.840180"
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<BlockActionsPayload>(json).unwrap();
+        assert_eq!(payload.enterprise.unwrap().name, "Example Org");
+        assert!(payload.is_enterprise_install);
+        assert!(payload.channel.is_none());
+        assert!(payload.message.is_none());
+        match payload.container.unwrap() {
+            Container::View { view_id } => assert_eq!(view_id, "V0PKB1ZFV"),
+            container => panic!("unexpected container: {:?}", container),
+        }
+        assert_eq!(
+            payload.view.unwrap().callback_id,
+            Some("new_ticket".to_string())
+        );
+
+        let text = payload.actions[0].text.as_ref().unwrap();
+        assert_eq!(text.text, "Approve");
+        assert_eq!(text.emoji, None);
+    }
 }
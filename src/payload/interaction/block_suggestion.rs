@@ -0,0 +1,148 @@
+use crate::composition::option::OptionObject;
+use crate::composition::option_group::OptionGroup;
+use crate::payload::interaction::block_actions::User;
+use crate::validation::{check_count, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
+
+/// Received at the options load URL when a user types in an `external_select` menu.
+///
+/// Reference:
+/// https://api.slack.com/reference/block-kit/block-elements#external_select
+#[derive(Debug, Deserialize)]
+pub struct BlockSuggestionPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: User,
+    pub api_app_id: Option<String>,
+    pub action_id: String,
+    pub block_id: String,
+    /// What the user has typed so far.
+    pub value: String,
+}
+
+/// Responded to `BlockSuggestionPayload` with the options to display.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::composition::option::OptionObject;
+/// use block_kit::payload::interaction::block_suggestion::OptionsResponse;
+///
+/// let response = OptionsResponse::Options(vec![OptionObject::new("text", "value")]);
+/// let json = serde_json::to_string(&response).unwrap();
+/// assert!(json.starts_with(r#"{"options":["#));
+/// ```
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptionsResponse {
+    Options(Vec<OptionObject>),
+    OptionGroups(Vec<OptionGroup>),
+}
+
+impl Validate for OptionsResponse {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        match self {
+            OptionsResponse::Options(options) => {
+                let path = field(path, "options");
+                check_count(path.clone(), options, 100, violations);
+                options.collect_violations(&path, violations);
+            }
+            OptionsResponse::OptionGroups(option_groups) => {
+                let path = field(path, "option_groups");
+                check_count(path.clone(), option_groups, 100, violations);
+                option_groups.collect_violations(&path, violations);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::ViolationKind::TooMany;
+
+    #[test]
+    fn test_de_payload() {
+        let json = r#"{
+  "type": "block_suggestion",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "api_app_id": "app_id",
+  "action_id": "action_id",
+  "block_id": "block_id",
+  "value": "tic"
+}"#;
+        let payload = serde_json::from_str::<BlockSuggestionPayload>(json).unwrap();
+        assert_eq!(payload.type_name, "block_suggestion");
+        assert_eq!(payload.user.id, "user_id");
+        assert_eq!(payload.api_app_id, Some("app_id".to_string()));
+        assert_eq!(payload.action_id, "action_id");
+        assert_eq!(payload.block_id, "block_id");
+        assert_eq!(payload.value, "tic");
+    }
+
+    #[test]
+    fn test_ser_options() {
+        let response = OptionsResponse::Options(vec![OptionObject::new("text", "value")]);
+        let json = serde_json::to_string_pretty(&response).unwrap_or("".to_string());
+        let expected = r#"{
+  "options": [
+    {
+      "text": {
+        "type": "plain_text",
+        "text": "text"
+      },
+      "value": "value"
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_option_groups() {
+        let group = OptionGroup::new("label", vec![OptionObject::new("text", "value")]);
+        let response = OptionsResponse::OptionGroups(vec![group]);
+        let json = serde_json::to_string_pretty(&response).unwrap_or("".to_string());
+        let expected = r#"{
+  "option_groups": [
+    {
+      "label": {
+        "type": "plain_text",
+        "text": "label"
+      },
+      "options": [
+        {
+          "text": {
+            "type": "plain_text",
+            "text": "text"
+          },
+          "value": "value"
+        }
+      ]
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let options = (0..101)
+            .map(|i| OptionObject::new("text", format!("value{}", i)))
+            .collect();
+        let response = OptionsResponse::Options(options);
+        assert_eq!(
+            response.validate(),
+            Err(vec![Violation {
+                path: "options".to_string(),
+                kind: TooMany {
+                    max: 100,
+                    actual: 101
+                },
+            }])
+        );
+    }
+}
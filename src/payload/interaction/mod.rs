@@ -1,7 +1,82 @@
+use crate::de::tagged;
+use crate::payload::interaction::block_actions::BlockActionsPayload;
+use crate::payload::interaction::block_suggestion::BlockSuggestionPayload;
+use crate::payload::interaction::shortcut::{GlobalShortcutPayload, MessageShortcutPayload};
+use crate::payload::interaction::view_closed::ViewClosedPayload;
+use crate::payload::interaction::view_submission::ViewSubmissionPayload;
+use crate::payload::interaction::InteractionPayload::{
+    BlockActions, BlockSuggestion, InteractiveMessage, MessageAction, Other, Shortcut, ViewClosed,
+    ViewSubmission,
+};
 use crate::payload::message::CommonMessagePayload;
-use serde::Serialize;
+use crate::validation::{Validate, Violation};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
 
 pub mod block_actions;
+pub mod block_suggestion;
+pub mod shortcut;
+pub mod view_closed;
+pub mod view_submission;
+
+const BLOCK_ACTIONS_TYPE: &str = "block_actions";
+const VIEW_SUBMISSION_TYPE: &str = "view_submission";
+const VIEW_CLOSED_TYPE: &str = "view_closed";
+const SHORTCUT_TYPE: &str = "shortcut";
+const MESSAGE_ACTION_TYPE: &str = "message_action";
+const BLOCK_SUGGESTION_TYPE: &str = "block_suggestion";
+const INTERACTIVE_MESSAGE_TYPE: &str = "interactive_message";
+
+/// Any payload sent to the request URL of interactivity,
+/// which is the `payload` parameter of the form.
+///
+/// Payloads of unknown `type` are kept as `Other` instead of failing.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::payload::interaction::InteractionPayload;
+///
+/// let json = r#"{ "type": "new_interaction", "user": { "id": "U123" } }"#;
+/// match serde_json::from_str::<InteractionPayload>(json).unwrap() {
+///     InteractionPayload::BlockActions(payload) => println!("{:?}", payload.actions),
+///     InteractionPayload::Other(value) => assert_eq!(value["type"], "new_interaction"),
+///     _ => {}
+/// }
+/// ```
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum InteractionPayload {
+    BlockActions(BlockActionsPayload),
+    ViewSubmission(ViewSubmissionPayload),
+    ViewClosed(ViewClosedPayload),
+    Shortcut(GlobalShortcutPayload),
+    MessageAction(MessageShortcutPayload),
+    BlockSuggestion(BlockSuggestionPayload),
+    /// Legacy interactive message with attachments.
+    InteractiveMessage(Value),
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for InteractionPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        let payload = match type_name.as_str() {
+            BLOCK_ACTIONS_TYPE => from_value(value).map(BlockActions),
+            VIEW_SUBMISSION_TYPE => from_value(value).map(ViewSubmission),
+            VIEW_CLOSED_TYPE => from_value(value).map(ViewClosed),
+            SHORTCUT_TYPE => from_value(value).map(Shortcut),
+            MESSAGE_ACTION_TYPE => from_value(value).map(MessageAction),
+            BLOCK_SUGGESTION_TYPE => from_value(value).map(BlockSuggestion),
+            INTERACTIVE_MESSAGE_TYPE => Ok(InteractiveMessage(value)),
+            _ => Ok(Other(value)),
+        };
+        payload.map_err(D::Error::custom)
+    }
+}
 
 /// Publishing messages back to the place where the interaction happened,
 /// using `response_url` which is in interactive component payload, like
@@ -52,6 +127,12 @@ impl InteractiveRespondPayload {
     }
 }
 
+impl Validate for InteractiveRespondPayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.payload.collect_violations(path, violations);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -84,4 +165,35 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_de_interaction() {
+        let json = r#"{
+  "type": "block_suggestion",
+  "user": {
+    "id": "user_id",
+    "username": "name",
+    "team_id": "team_id"
+  },
+  "action_id": "action_id",
+  "block_id": "block_id",
+  "value": "tic"
+}"#;
+        match serde_json::from_str::<InteractionPayload>(json).unwrap() {
+            BlockSuggestion(payload) => assert_eq!(payload.value, "tic"),
+            payload => panic!("unexpected payload: {:?}", payload),
+        }
+
+        let json = r#"{ "type": "view_closed", "user": {} }"#;
+        assert!(serde_json::from_str::<InteractionPayload>(json).is_err());
+
+        let json = r#"{ "type": "dialog_submission", "callback_id": "callback_id" }"#;
+        match serde_json::from_str::<InteractionPayload>(json).unwrap() {
+            Other(value) => assert_eq!(value["callback_id"], "callback_id"),
+            payload => panic!("unexpected payload: {:?}", payload),
+        }
+
+        let json = r#"{ "callback_id": "callback_id" }"#;
+        assert!(serde_json::from_str::<InteractionPayload>(json).is_err());
+    }
 }
@@ -0,0 +1,132 @@
+use crate::payload::interaction::block_actions::{Channel, Team, User};
+use crate::payload::message::Message;
+use serde::Deserialize;
+
+/// Received when a user invokes a global shortcut from the composer or search.
+///
+/// Reference:
+/// https://api.slack.com/reference/interaction-payloads/shortcuts#global
+#[derive(Debug, Deserialize)]
+pub struct GlobalShortcutPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub callback_id: String,
+    pub trigger_id: String,
+    pub user: User,
+    pub team: Option<Team>,
+    pub api_app_id: Option<String>,
+    pub action_ts: Option<String>,
+}
+
+/// Received when a user invokes a message shortcut from the menu of a message.
+///
+/// Reference:
+/// https://api.slack.com/reference/interaction-payloads/shortcuts#message
+#[derive(Debug, Deserialize)]
+pub struct MessageShortcutPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub callback_id: String,
+    pub trigger_id: String,
+    pub user: User,
+    pub team: Option<Team>,
+    pub channel: Channel,
+    /// The message the shortcut was invoked on.
+    pub message: Message,
+    pub message_ts: String,
+    pub response_url: String,
+    pub action_ts: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::Block;
+
+    #[test]
+    fn test_de_global() {
+        let json = r#"{
+  "type": "shortcut",
+  "token": "XXXXXXXXXXXXX",
+  "action_ts": "1581106241.371594",
+  "team": {
+    "id": "TXXXXXXXX",
+    "domain": "shortcuts-test"
+  },
+  "user": {
+    "id": "UXXXXXXXXX",
+    "username": "aman",
+    "team_id": "TXXXXXXXX"
+  },
+  "callback_id": "shortcut_create_task",
+  "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+}"#;
+        let payload = serde_json::from_str::<GlobalShortcutPayload>(json).unwrap();
+        assert_eq!(payload.callback_id, "shortcut_create_task");
+        assert_eq!(payload.user.username, "aman");
+        assert_eq!(payload.team.unwrap().domain, "shortcuts-test");
+    }
+
+    #[test]
+    fn test_de_message() {
+        let json = r#"{
+  "type": "message_action",
+  "token": "XXXXXXXXXXXXX",
+  "action_ts": "1581106241.371594",
+  "team": {
+    "id": "TXXXXXXXX",
+    "domain": "shortcuts-test"
+  },
+  "user": {
+    "id": "UXXXXXXXXX",
+    "username": "aman",
+    "team_id": "TXXXXXXXX",
+    "name": "aman"
+  },
+  "channel": {
+    "id": "CXXXXXXXXX",
+    "name": "general"
+  },
+  "callback_id": "create_ticket",
+  "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638",
+  "message_ts": "1581106222.000100",
+  "message": {
+    "type": "message",
+    "user": "UXXXXXXXXX",
+    "ts": "1581106222.000100",
+    "team": "TXXXXXXXX",
+    "text": "It's broken",
+    "blocks": [
+      {
+        "type": "section",
+        "block_id": "Ks2",
+        "text": {
+          "type": "mrkdwn",
+          "text": "It's broken",
+          "verbatim": false
+        }
+      },
+      {
+        "type": "call",
+        "block_id": "Ks3",
+        "call_id": "R00000000"
+      }
+    ]
+  },
+  "response_url": "https://hooks.slack.com/app/T00000000/0000000000/XXXXXXXX"
+}"#;
+        let payload = serde_json::from_str::<MessageShortcutPayload>(json).unwrap();
+        assert_eq!(payload.callback_id, "create_ticket");
+        assert_eq!(payload.channel.name, "general");
+        assert_eq!(payload.message.text, "It's broken");
+        assert_eq!(payload.message.user.as_deref(), Some("UXXXXXXXXX"));
+        match &payload.message.blocks[0] {
+            Block::Section(_) => {}
+            block => panic!("unexpected block: {:?}", block),
+        }
+        match &payload.message.blocks[1] {
+            Block::Unknown(value) => assert_eq!(value["call_id"], "R00000000"),
+            block => panic!("unexpected block: {:?}", block),
+        }
+    }
+}
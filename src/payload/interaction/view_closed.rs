@@ -0,0 +1,59 @@
+use crate::payload::interaction::block_actions::User;
+use crate::payload::view::View;
+use serde::Deserialize;
+
+/// Received when a modal with `notify_on_close` is closed by the user.
+///
+/// Reference:
+/// https://api.slack.com/reference/interaction-payloads/views#view_closed
+#[derive(Debug, Deserialize)]
+pub struct ViewClosedPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: User,
+    pub api_app_id: Option<String>,
+    pub view: View,
+    /// Whether the whole view stack was closed by `clear_on_close`.
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_payload() {
+        let json = r#"{
+  "type": "view_closed",
+  "team": {
+    "id": "T123",
+    "domain": "example"
+  },
+  "user": {
+    "id": "U123",
+    "username": "name",
+    "name": "name",
+    "team_id": "T123"
+  },
+  "api_app_id": "A123",
+  "view": {
+    "id": "V123",
+    "type": "modal",
+    "blocks": [],
+    "private_metadata": "",
+    "callback_id": "new_ticket",
+    "state": {
+      "values": {}
+    },
+    "hash": "156663117.cd33ad1f"
+  },
+  "is_cleared": false
+}"#;
+        let payload = serde_json::from_str::<ViewClosedPayload>(json).unwrap();
+        assert_eq!(payload.type_name, "view_closed");
+        assert_eq!(payload.view.id, "V123");
+        assert!(payload.view.state.unwrap().values.is_empty());
+        assert!(!payload.is_cleared);
+    }
+}
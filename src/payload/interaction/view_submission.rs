@@ -0,0 +1,344 @@
+use crate::payload::interaction::block_actions::User;
+use crate::payload::view::View;
+use crate::validation::{field, Validate, Violation};
+use crate::view::modal::ModalView;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Received when a modal is submitted.
+/// Values of input blocks are in `view.state`.
+///
+/// Reference:
+/// https://api.slack.com/reference/interaction-payloads/views#view_submission
+#[derive(Debug, Deserialize)]
+pub struct ViewSubmissionPayload {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub user: User,
+    pub api_app_id: Option<String>,
+    pub trigger_id: Option<String>,
+    pub view: View,
+    /// Included for elements with `response_url_enabled`.
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponseUrl {
+    pub block_id: String,
+    pub action_id: String,
+    pub channel_id: String,
+    pub response_url: String,
+}
+
+/// Responded to `ViewSubmissionPayload` as the body of the HTTP response,
+/// within 3 seconds. An empty body just closes the modal.
+///
+/// Reference:
+/// https://api.slack.com/surfaces/modals/using#modifying
+///
+/// # Example:
+/// ```rust
+/// use block_kit::payload::interaction::view_submission::ViewSubmissionResponse;
+/// use std::collections::HashMap;
+///
+/// let mut errors = HashMap::new();
+/// errors.insert("title_block".to_string(), "Title is required".to_string());
+/// let response = ViewSubmissionResponse::Errors { errors };
+///
+/// let json = serde_json::to_string(&response).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"response_action":"errors","errors":{"title_block":"Title is required"}}"#
+/// );
+/// ```
+#[derive(Debug, Serialize)]
+#[serde(tag = "response_action", rename_all = "snake_case")]
+pub enum ViewSubmissionResponse {
+    /// Displays error messages next to the input blocks, keyed by `block_id`.
+    Errors { errors: HashMap<String, String> },
+    /// Updates the submitted view.
+    Update { view: ModalView },
+    /// Pushes a new view onto the view stack.
+    Push { view: ModalView },
+    /// Closes all views in the view stack.
+    Clear,
+}
+
+impl Validate for ViewSubmissionResponse {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        match self {
+            ViewSubmissionResponse::Update { view } | ViewSubmissionResponse::Push { view } => {
+                view.collect_violations(&field(path, "view"), violations)
+            }
+            ViewSubmissionResponse::Errors { .. } | ViewSubmissionResponse::Clear => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::input::{InputBlock, InputBlockElement};
+    use crate::block::Block;
+    use crate::payload::view::StateValue;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[test]
+    fn test_de_payload() {
+        let json = r#"{
+  "type": "view_submission",
+  "team": {
+    "id": "T123",
+    "domain": "example"
+  },
+  "user": {
+    "id": "U123",
+    "username": "name",
+    "name": "name",
+    "team_id": "T123"
+  },
+  "api_app_id": "A123",
+  "token": "token",
+  "trigger_id": "trigger_id",
+  "view": {
+    "id": "V123",
+    "team_id": "T123",
+    "type": "modal",
+    "blocks": [
+      {
+        "type": "input",
+        "block_id": "title_block",
+        "label": {
+          "type": "plain_text",
+          "text": "Title",
+          "emoji": true
+        },
+        "optional": false,
+        "dispatch_action": false,
+        "element": {
+          "type": "plain_text_input",
+          "action_id": "title",
+          "dispatch_action_config": {
+            "trigger_actions_on": ["on_enter_pressed"]
+          }
+        }
+      },
+      {
+        "type": "input",
+        "block_id": "channel_block",
+        "label": {
+          "type": "plain_text",
+          "text": "Channel",
+          "emoji": true
+        },
+        "element": {
+          "type": "conversations_select",
+          "action_id": "channel",
+          "placeholder": {
+            "type": "plain_text",
+            "text": "Select",
+            "emoji": true
+          },
+          "response_url_enabled": true
+        }
+      }
+    ],
+    "private_metadata": "ticket",
+    "callback_id": "new_ticket",
+    "state": {
+      "values": {
+        "title_block": {
+          "title": {
+            "type": "plain_text_input",
+            "value": "Broken build"
+          }
+        },
+        "channel_block": {
+          "channel": {
+            "type": "conversations_select",
+            "selected_conversation": "C123"
+          }
+        }
+      }
+    },
+    "hash": "156663117.cd33ad1f",
+    "title": {
+      "type": "plain_text",
+      "text": "New ticket",
+      "emoji": true
+    },
+    "clear_on_close": false,
+    "notify_on_close": false,
+    "close": null,
+    "submit": {
+      "type": "plain_text",
+      "text": "Create",
+      "emoji": true
+    },
+    "previous_view_id": null,
+    "root_view_id": "V123",
+    "app_id": "A123",
+    "external_id": "",
+    "app_installed_team_id": "T123",
+    "bot_id": "B123"
+  },
+  "response_urls": [
+    {
+      "block_id": "channel_block",
+      "action_id": "channel",
+      "channel_id": "C123",
+      "response_url": "https://hooks.slack.com/app/T123/1/abc"
+    }
+  ]
+}"#;
+        let payload = serde_json::from_str::<ViewSubmissionPayload>(json).unwrap();
+        assert_eq!(payload.type_name, "view_submission");
+        assert_eq!(payload.user.id, "U123");
+        assert_eq!(payload.view.callback_id, Some("new_ticket".to_string()));
+        assert_eq!(payload.view.blocks.len(), 2);
+        assert_eq!(payload.response_urls[0].channel_id, "C123");
+
+        let state = payload.view.state.unwrap();
+        match &state.values["title_block"]["title"] {
+            StateValue::PlainTextInput { value } => {
+                assert_eq!(value, &Some("Broken build".to_string()))
+            }
+            value => panic!("unexpected value: {:?}", value),
+        }
+
+        #[derive(Deserialize)]
+        struct Ticket {
+            title: String,
+            channel: String,
+        }
+        let ticket = state.extract::<Ticket>().unwrap();
+        assert_eq!(ticket.title, "Broken build");
+        assert_eq!(ticket.channel, "C123");
+    }
+
+    #[test]
+    fn test_de_unknown_blocks() {
+        let json = r#"{
+  "type": "view_submission",
+  "team": {
+    "id": "T123",
+    "domain": "example"
+  },
+  "user": {
+    "id": "U123",
+    "username": "name",
+    "team_id": "T123"
+  },
+  "api_app_id": "A123",
+  "token": "token",
+  "trigger_id": "trigger_id",
+  "view": {
+    "id": "V123",
+    "type": "modal",
+    "blocks": [
+      {
+        "type": "input",
+        "block_id": "body_block",
+        "label": {
+          "type": "plain_text",
+          "text": "Body"
+        },
+        "element": {
+          "type": "rich_text_input",
+          "action_id": "body"
+        }
+      },
+      {
+        "type": "input",
+        "block_id": "file_block",
+        "label": {
+          "type": "plain_text",
+          "text": "Attachments"
+        },
+        "element": {
+          "type": "file_input",
+          "action_id": "files",
+          "max_files": 5
+        }
+      },
+      {
+        "type": "call",
+        "block_id": "call_block",
+        "call_id": "R123"
+      }
+    ],
+    "state": {
+      "values": {
+        "body_block": {
+          "body": {
+            "type": "rich_text_input",
+            "rich_text_value": {
+              "type": "rich_text",
+              "elements": []
+            }
+          }
+        }
+      }
+    },
+    "title": {
+      "type": "plain_text",
+      "text": "New ticket"
+    }
+  }
+}"#;
+        let payload = serde_json::from_str::<ViewSubmissionPayload>(json).unwrap();
+        let blocks = &payload.view.blocks;
+        assert_eq!(blocks.len(), 3);
+        match &blocks[0] {
+            Block::Input(input) => assert_eq!(
+                input,
+                &InputBlock::new(
+                    "Body",
+                    InputBlockElement::Unknown(json!({
+                        "type": "rich_text_input",
+                        "action_id": "body"
+                    }))
+                )
+                .block_id("body_block")
+            ),
+            block => panic!("unexpected block: {:?}", block),
+        }
+        match &blocks[2] {
+            Block::Unknown(value) => assert_eq!(value["call_id"], "R123"),
+            block => panic!("unexpected block: {:?}", block),
+        }
+        match &payload.view.state.unwrap().values["body_block"]["body"] {
+            StateValue::Unknown(value) => assert_eq!(value["rich_text_value"]["type"], "rich_text"),
+            value => panic!("unexpected value: {:?}", value),
+        }
+    }
+
+    #[test]
+    fn test_ser_response() {
+        let view = ModalView::new("title", vec![]);
+        let response = ViewSubmissionResponse::Update { view: view.clone() };
+        let json = serde_json::to_string_pretty(&response).unwrap();
+        let expected = r#"{
+  "response_action": "update",
+  "view": {
+    "type": "modal",
+    "title": {
+      "type": "plain_text",
+      "text": "title"
+    },
+    "blocks": []
+  }
+}"#;
+        assert_eq!(json, expected);
+
+        let response = ViewSubmissionResponse::Push { view };
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.starts_with(r#"{"response_action":"push","view":{"#));
+
+        let response = ViewSubmissionResponse::Clear;
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(json, r#"{"response_action":"clear"}"#);
+    }
+}
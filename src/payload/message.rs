@@ -1,6 +1,7 @@
 use crate::attachment::Attachment;
 use crate::block::Block;
-use serde::Serialize;
+use crate::validation::{check_count, field, Validate, Violation};
+use serde::{Deserialize, Serialize};
 
 /// Common base structure for Slack APIs that publish message.
 /// Some additional fields may be required.
@@ -59,6 +60,38 @@ impl CommonMessagePayload {
     }
 }
 
+/// Messages can have up to 50 blocks.
+impl Validate for CommonMessagePayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_count(field(path, "blocks"), &self.blocks, 50, violations);
+        self.blocks
+            .collect_violations(&field(path, "blocks"), violations);
+        self.attachments
+            .collect_violations(&field(path, "attachments"), violations);
+    }
+}
+
+/// A message as received in interaction and event payloads.
+///
+/// Reference:
+/// https://api.slack.com/events/message
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub subtype: Option<String>,
+    /// Not included in messages posted by bots.
+    pub user: Option<String>,
+    pub bot_id: Option<String>,
+    pub team: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    pub ts: String,
+    pub thread_ts: Option<String>,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -68,6 +101,8 @@ mod test {
     use crate::block::Block::{Divider, Image, Section};
     use crate::composition::text::PlainText;
     use crate::composition::text::Text::Plain;
+    use crate::validation::Violation;
+    use crate::validation::ViolationKind::{TooLong, TooMany};
 
     #[test]
     fn test_ser_blocks() {
@@ -159,4 +194,38 @@ mod test {
 }"#;
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn test_validate_blocks() {
+        let blocks: Vec<Block> = (0..51).map(|_| Divider(DividerBlock::new())).collect();
+        let payload = CommonMessagePayload::new().blocks(blocks);
+        assert_eq!(
+            payload.validate(),
+            Err(vec![Violation {
+                path: "blocks".to_string(),
+                kind: TooMany {
+                    max: 50,
+                    actual: 51
+                },
+            }])
+        );
+
+        let blocks = vec![
+            Divider(DividerBlock::new()),
+            Section(SectionBlock::new(Plain(PlainText::new("a".repeat(3001))))),
+        ];
+        let payload = CommonMessagePayload::new()
+            .attachments(vec![Attachment::new(blocks)])
+            .blocks(vec![Divider(DividerBlock::new())]);
+        assert_eq!(
+            payload.validate(),
+            Err(vec![Violation {
+                path: "attachments[0].blocks[1].text.text".to_string(),
+                kind: TooLong {
+                    max: 3000,
+                    actual: 3001
+                },
+            }])
+        );
+    }
 }
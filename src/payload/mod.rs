@@ -1,4 +1,7 @@
 pub mod chat;
+pub mod event;
 pub mod interaction;
 pub mod message;
 pub mod slash_command;
+pub mod view;
+pub mod views;
@@ -0,0 +1,425 @@
+use crate::block::Block;
+use crate::block_element::date_time::{Date, Time};
+use crate::de::tagged;
+use crate::payload::interaction::block_actions::SelectedOption;
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+
+/// A view as received in interaction and event payloads,
+/// including fields added by Slack like `id`, `hash` and `state`.
+///
+/// Reference:
+/// https://api.slack.com/reference/interaction-payloads/views
+#[derive(Debug, Deserialize)]
+pub struct View {
+    pub id: String,
+    pub team_id: Option<String>,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    pub private_metadata: Option<String>,
+    pub callback_id: Option<String>,
+    pub external_id: Option<String>,
+    pub state: Option<ViewState>,
+    /// Pass it to `views.update` or `views.publish` to avoid race conditions.
+    pub hash: Option<String>,
+    pub root_view_id: Option<String>,
+    pub previous_view_id: Option<String>,
+    pub app_id: Option<String>,
+    pub bot_id: Option<String>,
+}
+
+/// Values of the interactive elements in the view.
+#[derive(Debug, Deserialize)]
+pub struct ViewState {
+    /// Keyed by `block_id`, and then by `action_id`.
+    pub values: HashMap<String, HashMap<String, StateValue>>,
+}
+
+/// The `type`s of the elements which `StateValue` supports.
+const STATE_VALUE_TYPES: &[&str] = &[
+    "plain_text_input",
+    "email_text_input",
+    "url_text_input",
+    "number_input",
+    "static_select",
+    "external_select",
+    "multi_static_select",
+    "users_select",
+    "multi_users_select",
+    "conversations_select",
+    "multi_conversations_select",
+    "channels_select",
+    "multi_channels_select",
+    "checkboxes",
+    "radio_buttons",
+    "datepicker",
+    "timepicker",
+    "datetimepicker",
+];
+
+/// A value of an element in `ViewState`, by the type of the element.
+#[derive(Debug, Deserialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
+pub enum StateValue {
+    PlainTextInput {
+        value: Option<String>,
+    },
+    EmailTextInput {
+        value: Option<String>,
+    },
+    UrlTextInput {
+        value: Option<String>,
+    },
+    NumberInput {
+        value: Option<String>,
+    },
+    StaticSelect {
+        selected_option: Option<SelectedOption>,
+    },
+    ExternalSelect {
+        selected_option: Option<SelectedOption>,
+    },
+    MultiStaticSelect {
+        #[serde(default)]
+        selected_options: Vec<SelectedOption>,
+    },
+    UsersSelect {
+        selected_user: Option<String>,
+    },
+    MultiUsersSelect {
+        #[serde(default)]
+        selected_users: Vec<String>,
+    },
+    ConversationsSelect {
+        selected_conversation: Option<String>,
+    },
+    MultiConversationsSelect {
+        #[serde(default)]
+        selected_conversations: Vec<String>,
+    },
+    ChannelsSelect {
+        selected_channel: Option<String>,
+    },
+    MultiChannelsSelect {
+        #[serde(default)]
+        selected_channels: Vec<String>,
+    },
+    Checkboxes {
+        #[serde(default)]
+        selected_options: Vec<SelectedOption>,
+    },
+    RadioButtons {
+        selected_option: Option<SelectedOption>,
+    },
+    #[serde(rename = "datepicker")]
+    DatePicker {
+        selected_date: Option<Date>,
+    },
+    #[serde(rename = "timepicker")]
+    TimePicker {
+        selected_time: Option<Time>,
+    },
+    #[serde(rename = "datetimepicker")]
+    DateTimePicker {
+        selected_date_time: Option<i64>,
+    },
+    /// Elements which are not supported by this crate, kept as they are received.
+    #[serde(skip_deserializing)]
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for StateValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (type_name, value) = tagged(deserializer)?;
+        if STATE_VALUE_TYPES.contains(&type_name.as_str()) {
+            StateValue::deserialize(value).map_err(D::Error::custom)
+        } else {
+            Ok(StateValue::Unknown(value))
+        }
+    }
+}
+
+impl ViewState {
+    /// Deserializes the values into `T`, whose fields are named after `action_id`s.
+    ///
+    /// Each value is simplified to JSON before deserializing;
+    /// the `value` of selected options, IDs for users or conversations,
+    /// strings for texts, dates and times, and numbers for number inputs and timestamps.
+    /// Number inputs which are not numbers are kept as strings,
+    /// and elements which are not supported are kept as they are received.
+    /// Elements with no input are `null`, so use `Option` for optional inputs.
+    ///
+    /// Fails when the same `action_id` is used in more than one block,
+    /// because it is ambiguous which value the field should have.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use block_kit::payload::view::ViewState;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Ticket {
+    ///     title: String,
+    ///     priority: String,
+    ///     due: Option<String>,
+    /// }
+    ///
+    /// let json = r#"{
+    ///   "values": {
+    ///     "title_block": {
+    ///       "title": { "type": "plain_text_input", "value": "Broken build" }
+    ///     },
+    ///     "priority_block": {
+    ///       "priority": {
+    ///         "type": "static_select",
+    ///         "selected_option": {
+    ///           "text": { "type": "plain_text", "text": "High", "emoji": true },
+    ///           "value": "high"
+    ///         }
+    ///       }
+    ///     },
+    ///     "due_block": {
+    ///       "due": { "type": "datepicker", "selected_date": null }
+    ///     }
+    ///   }
+    /// }"#;
+    /// let state = serde_json::from_str::<ViewState>(json).unwrap();
+    ///
+    /// let ticket = state.extract::<Ticket>().unwrap();
+    /// assert_eq!(ticket.title, "Broken build");
+    /// assert_eq!(ticket.priority, "high");
+    /// assert_eq!(ticket.due, None);
+    /// ```
+    pub fn extract<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let mut map = Map::new();
+        for actions in self.values.values() {
+            for (action_id, value) in actions {
+                if map.insert(action_id.clone(), value.to_json()).is_some() {
+                    return Err(Error::custom(format!(
+                        "duplicate action_id `{}` in more than one block",
+                        action_id
+                    )));
+                }
+            }
+        }
+        serde_json::from_value(Value::Object(map))
+    }
+}
+
+impl StateValue {
+    fn to_json(&self) -> Value {
+        fn string(value: &Option<String>) -> Value {
+            value.clone().map(Value::String).unwrap_or(Value::Null)
+        }
+        fn option(option: &Option<SelectedOption>) -> Value {
+            string(&option.as_ref().map(|option| option.value.clone()))
+        }
+        fn options(options: &[SelectedOption]) -> Value {
+            options
+                .iter()
+                .map(|option| Value::String(option.value.clone()))
+                .collect()
+        }
+        fn strings(values: &[String]) -> Value {
+            values.iter().cloned().map(Value::String).collect()
+        }
+
+        match self {
+            StateValue::PlainTextInput { value }
+            | StateValue::EmailTextInput { value }
+            | StateValue::UrlTextInput { value } => string(value),
+            StateValue::NumberInput { value } => match value {
+                Some(value) => value
+                    .parse::<Number>()
+                    .map(Value::Number)
+                    .unwrap_or_else(|_| Value::String(value.clone())),
+                None => Value::Null,
+            },
+            StateValue::StaticSelect { selected_option }
+            | StateValue::ExternalSelect { selected_option }
+            | StateValue::RadioButtons { selected_option } => option(selected_option),
+            StateValue::MultiStaticSelect { selected_options }
+            | StateValue::Checkboxes { selected_options } => options(selected_options),
+            StateValue::UsersSelect { selected_user } => string(selected_user),
+            StateValue::MultiUsersSelect { selected_users } => strings(selected_users),
+            StateValue::ConversationsSelect {
+                selected_conversation,
+            } => string(selected_conversation),
+            StateValue::MultiConversationsSelect {
+                selected_conversations,
+            } => strings(selected_conversations),
+            StateValue::ChannelsSelect { selected_channel } => string(selected_channel),
+            StateValue::MultiChannelsSelect { selected_channels } => strings(selected_channels),
+            StateValue::DatePicker { selected_date } => {
+                string(&selected_date.as_ref().map(Date::to_string))
+            }
+            StateValue::TimePicker { selected_time } => {
+                string(&selected_time.as_ref().map(Time::to_string))
+            }
+            StateValue::DateTimePicker { selected_date_time } => {
+                selected_date_time.map(Value::from).unwrap_or(Value::Null)
+            }
+            StateValue::Unknown(value) => value.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_de_home() {
+        let json = r#"{
+  "id": "V123",
+  "team_id": "T123",
+  "type": "home",
+  "blocks": [
+    {
+      "type": "divider",
+      "block_id": "div"
+    }
+  ],
+  "private_metadata": "",
+  "callback_id": "",
+  "state": {
+    "values": {}
+  },
+  "hash": "1605664221.a5fd3aa8",
+  "title": {
+    "type": "plain_text",
+    "text": "View Title",
+    "emoji": true
+  },
+  "clear_on_close": false,
+  "notify_on_close": false,
+  "close": null,
+  "submit": null,
+  "previous_view_id": null,
+  "root_view_id": "V123",
+  "app_id": "A123",
+  "external_id": "",
+  "app_installed_team_id": "T123",
+  "bot_id": "B123"
+}"#;
+        let view = serde_json::from_str::<View>(json).unwrap();
+        assert_eq!(view.id, "V123");
+        assert_eq!(view.type_name, "home");
+        assert_eq!(view.blocks.len(), 1);
+        assert_eq!(view.hash, Some("1605664221.a5fd3aa8".to_string()));
+        assert_eq!(view.previous_view_id, None);
+    }
+
+    #[test]
+    fn test_de_state() {
+        let json = r#"{
+  "values": {
+    "b1": {
+      "count": { "type": "number_input", "value": "3" },
+      "members": { "type": "multi_users_select", "selected_users": ["U1", "U2"] },
+      "time": { "type": "timepicker", "selected_time": "09:30" },
+      "unknown": { "type": "rich_text_input", "rich_text_value": {} }
+    },
+    "b2": {
+      "check": { "type": "checkboxes", "selected_options": [] },
+      "at": { "type": "datetimepicker", "selected_date_time": 1628633820 }
+    }
+  }
+}"#;
+        let state = serde_json::from_str::<ViewState>(json).unwrap();
+        match &state.values["b1"]["time"] {
+            StateValue::TimePicker { selected_time } => {
+                assert_eq!(selected_time, &Some(Time::from_hm(9, 30).unwrap()))
+            }
+            value => panic!("unexpected value: {:?}", value),
+        }
+        match &state.values["b1"]["unknown"] {
+            StateValue::Unknown(value) => assert_eq!(value["type"], "rich_text_input"),
+            value => panic!("unexpected value: {:?}", value),
+        }
+
+        #[derive(Deserialize)]
+        struct Form {
+            count: u32,
+            members: Vec<String>,
+            time: Time,
+            check: Vec<String>,
+            at: i64,
+        }
+        let form = state.extract::<Form>().unwrap();
+        assert_eq!(form.count, 3);
+        assert_eq!(form.members, vec!["U1", "U2"]);
+        assert_eq!(form.time.to_string(), "09:30");
+        assert!(form.check.is_empty());
+        assert_eq!(form.at, 1628633820);
+    }
+
+    #[test]
+    fn test_de_state_invalid() {
+        let json = r#"{
+  "values": {
+    "b1": {
+      "time": { "type": "timepicker", "selected_time": "25:00" }
+    }
+  }
+}"#;
+        assert!(serde_json::from_str::<ViewState>(json).is_err());
+    }
+
+    #[test]
+    fn test_extract_raw_values() {
+        let json = r#"{
+  "values": {
+    "b1": {
+      "count": { "type": "number_input", "value": "three" },
+      "body": {
+        "type": "rich_text_input",
+        "rich_text_value": { "type": "rich_text", "elements": [] }
+      }
+    }
+  }
+}"#;
+        let state = serde_json::from_str::<ViewState>(json).unwrap();
+        let values = state.extract::<Value>().unwrap();
+        assert_eq!(values["count"], "three");
+        assert_eq!(values["body"]["rich_text_value"]["type"], "rich_text");
+
+        #[derive(Debug, Deserialize)]
+        struct Form {
+            #[allow(dead_code)]
+            count: u32,
+        }
+        let error = state.extract::<Form>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid type: string \"three\", expected u32"
+        );
+    }
+
+    #[test]
+    fn test_extract_duplicate_action_id() {
+        let json = r#"{
+  "values": {
+    "b1": {
+      "title": { "type": "plain_text_input", "value": "first" }
+    },
+    "b2": {
+      "title": { "type": "plain_text_input", "value": "second" }
+    }
+  }
+}"#;
+        let state = serde_json::from_str::<ViewState>(json).unwrap();
+        let error = state.extract::<Value>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "duplicate action_id `title` in more than one block"
+        );
+    }
+}
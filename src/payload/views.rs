@@ -0,0 +1,246 @@
+use crate::validation::{check_optional_length, field, Validate, Violation};
+use crate::view::home::HomeView;
+use crate::view::modal::ModalView;
+use serde::Serialize;
+
+/// Reference:
+/// https://api.slack.com/methods/views.open
+///
+/// # views.open api's payload.
+/// `trigger_id` is available for 3 seconds after the interaction.
+#[derive(Debug, Serialize)]
+pub struct ViewsOpenPayload {
+    trigger_id: String,
+    view: ModalView,
+}
+
+impl ViewsOpenPayload {
+    pub fn new(trigger_id: impl Into<String>, view: ModalView) -> Self {
+        ViewsOpenPayload {
+            trigger_id: trigger_id.into(),
+            view,
+        }
+    }
+}
+
+impl Validate for ViewsOpenPayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.view
+            .collect_violations(&field(path, "view"), violations);
+    }
+}
+
+/// Reference:
+/// https://api.slack.com/methods/views.push
+///
+/// # views.push api's payload.
+/// Pushes a view onto the stack of an open modal. The stack can have up to 3 views.
+#[derive(Debug, Serialize)]
+pub struct ViewsPushPayload {
+    trigger_id: String,
+    view: ModalView,
+}
+
+impl ViewsPushPayload {
+    pub fn new(trigger_id: impl Into<String>, view: ModalView) -> Self {
+        ViewsPushPayload {
+            trigger_id: trigger_id.into(),
+            view,
+        }
+    }
+}
+
+impl Validate for ViewsPushPayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.view
+            .collect_violations(&field(path, "view"), violations);
+    }
+}
+
+/// Reference:
+/// https://api.slack.com/methods/views.update
+///
+/// # views.update api's payload.
+/// The view to update is specified by either `view_id` or `external_id`.
+///
+/// `hash` is the one of the latest view, like in `block_actions` payloads.
+/// Slack answers `hash_conflict` if the view has been updated since,
+/// which protects against race conditions.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::payload::views::ViewsUpdatePayload;
+/// use block_kit::view::modal::ModalView;
+///
+/// let view = ModalView::new("title", vec![]);
+/// let payload = ViewsUpdatePayload::by_view_id("V123", view).hash("156772938.1827394");
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ViewsUpdatePayload {
+    view: ModalView,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    view_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+}
+
+impl ViewsUpdatePayload {
+    pub fn by_view_id(view_id: impl Into<String>, view: ModalView) -> Self {
+        ViewsUpdatePayload {
+            view,
+            view_id: Some(view_id.into()),
+            external_id: Option::default(),
+            hash: Option::default(),
+        }
+    }
+
+    pub fn by_external_id(external_id: impl Into<String>, view: ModalView) -> Self {
+        ViewsUpdatePayload {
+            view,
+            view_id: Option::default(),
+            external_id: Some(external_id.into()),
+            hash: Option::default(),
+        }
+    }
+
+    pub fn hash(mut self, hash: impl Into<String>) -> Self {
+        self.hash = Some(hash.into());
+        self
+    }
+}
+
+impl Validate for ViewsUpdatePayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.view
+            .collect_violations(&field(path, "view"), violations);
+        check_optional_length(
+            field(path, "external_id"),
+            &self.external_id,
+            255,
+            violations,
+        );
+    }
+}
+
+/// Reference:
+/// https://api.slack.com/methods/views.publish
+///
+/// # views.publish api's payload.
+/// Publishes the Home tab of `user_id`. `hash` works as in `ViewsUpdatePayload`.
+#[derive(Debug, Serialize)]
+pub struct ViewsPublishPayload {
+    user_id: String,
+    view: HomeView,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+}
+
+impl ViewsPublishPayload {
+    pub fn new(user_id: impl Into<String>, view: HomeView) -> Self {
+        ViewsPublishPayload {
+            user_id: user_id.into(),
+            view,
+            hash: Option::default(),
+        }
+    }
+
+    pub fn hash(mut self, hash: impl Into<String>) -> Self {
+        self.hash = Some(hash.into());
+        self
+    }
+}
+
+impl Validate for ViewsPublishPayload {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        self.view
+            .collect_violations(&field(path, "view"), violations);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::divider::DividerBlock;
+
+    #[test]
+    fn test_ser_open() {
+        let view = ModalView::new("title", vec![DividerBlock::new().into()]);
+        let payload = ViewsOpenPayload::new("trigger_id", view);
+        let json = serde_json::to_string_pretty(&payload).unwrap();
+
+        let expected = r#"{
+  "trigger_id": "trigger_id",
+  "view": {
+    "type": "modal",
+    "title": {
+      "type": "plain_text",
+      "text": "title"
+    },
+    "blocks": [
+      {
+        "type": "divider"
+      }
+    ]
+  }
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_push() {
+        let view = ModalView::new("title", vec![]);
+        let payload = ViewsPushPayload::new("trigger_id", view);
+        let json = serde_json::to_string(&payload).unwrap();
+
+        let expected = r#"{"trigger_id":"trigger_id","view":{"type":"modal","title":{"type":"plain_text","text":"title"},"blocks":[]}}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_update() {
+        let view = ModalView::new("title", vec![]);
+        let payload = ViewsUpdatePayload::by_view_id("V123", view.clone()).hash("hash");
+        let json = serde_json::to_string(&payload).unwrap();
+
+        let expected = r#"{"view":{"type":"modal","title":{"type":"plain_text","text":"title"},"blocks":[]},"view_id":"V123","hash":"hash"}"#;
+        assert_eq!(json, expected);
+
+        let payload = ViewsUpdatePayload::by_external_id("external", view);
+        let json = serde_json::to_string(&payload).unwrap();
+
+        let expected = r#"{"view":{"type":"modal","title":{"type":"plain_text","text":"title"},"blocks":[]},"external_id":"external"}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let view = ModalView::new("a".repeat(25), vec![]);
+        let violations = ViewsOpenPayload::new("trigger_id", view)
+            .validate()
+            .unwrap_err();
+        assert_eq!(violations[0].path, "view.title.text");
+    }
+
+    #[test]
+    fn test_ser_publish() {
+        let view = HomeView::new(vec![DividerBlock::new().into()]);
+        let payload = ViewsPublishPayload::new("U123", view).hash("hash");
+        let json = serde_json::to_string_pretty(&payload).unwrap();
+
+        let expected = r#"{
+  "user_id": "U123",
+  "view": {
+    "type": "home",
+    "blocks": [
+      {
+        "type": "divider"
+      }
+    ]
+  },
+  "hash": "hash"
+}"#;
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,198 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+const VERSION: &str = "v0";
+const DEFAULT_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// Verifies that a request was sent by Slack, using the signing secret of the app.
+///
+/// Reference:
+/// https://api.slack.com/authentication/verifying-requests-from-slack
+///
+/// # Example:
+/// ```rust
+/// use block_kit::signature::{SignatureVerifier, VerificationError};
+///
+/// let verifier = SignatureVerifier::new("8f742231b10e8888abcd99yyyzzz85a5");
+/// let signature = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+///
+/// // Requests older than 5 minutes are rejected.
+/// let result = verifier.verify("1531420618", signature, b"body");
+/// assert_eq!(result, Err(VerificationError::StaleTimestamp(1531420618)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SignatureVerifier {
+    signing_secret: String,
+    tolerance: Duration,
+}
+
+impl SignatureVerifier {
+    pub fn new(signing_secret: impl Into<String>) -> Self {
+        SignatureVerifier {
+            signing_secret: signing_secret.into(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// How far the timestamp can be from now, to protect against replay attacks.
+    /// Default is 5 minutes.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// `timestamp` and `signature` are the values of `TIMESTAMP_HEADER` and `SIGNATURE_HEADER`,
+    /// and `body` is the raw request body before any parsing.
+    pub fn verify(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), VerificationError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        self.verify_at(timestamp, signature, body, now)
+    }
+
+    fn verify_at(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+        now: u64,
+    ) -> Result<(), VerificationError> {
+        let seconds = timestamp
+            .parse::<u64>()
+            .map_err(|_| VerificationError::InvalidTimestamp(timestamp.to_string()))?;
+        if seconds.max(now) - seconds.min(now) > self.tolerance.as_secs() {
+            return Err(VerificationError::StaleTimestamp(seconds));
+        }
+
+        let expected = signature
+            .strip_prefix(VERSION)
+            .and_then(|rest| rest.strip_prefix('='))
+            .and_then(|hash| hex::decode(hash).ok())
+            .ok_or_else(|| VerificationError::InvalidSignature(signature.to_string()))?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.signing_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}:{}:", VERSION, timestamp).as_bytes());
+        mac.update(body);
+        mac.verify_slice(&expected)
+            .map_err(|_| VerificationError::SignatureMismatch)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum VerificationError {
+    /// The timestamp header is not Unix time in seconds.
+    InvalidTimestamp(String),
+    /// The timestamp is out of the tolerance.
+    StaleTimestamp(u64),
+    /// The signature header is not in the format `v0=<hex>`.
+    InvalidSignature(String),
+    /// The signature does not match the body.
+    SignatureMismatch,
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::InvalidTimestamp(timestamp) => {
+                write!(f, "`{}` is not a valid timestamp", timestamp)
+            }
+            VerificationError::StaleTimestamp(timestamp) => {
+                write!(f, "timestamp {} is too old or in the future", timestamp)
+            }
+            VerificationError::InvalidSignature(signature) => {
+                write!(f, "`{}` does not match the format v0=<hex>", signature)
+            }
+            VerificationError::SignatureMismatch => write!(f, "signature does not match"),
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Example of the official docs.
+    const SIGNING_SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    #[test]
+    fn test_verify() {
+        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let now = 1531420618 + 60;
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), now),
+            Ok(())
+        );
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, SIGNATURE, b"token=forged", now),
+            Err(VerificationError::SignatureMismatch)
+        );
+
+        let verifier = SignatureVerifier::new("forged_secret");
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), now),
+            Err(VerificationError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn test_verify_timestamp() {
+        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 + 301),
+            Err(VerificationError::StaleTimestamp(1531420618))
+        );
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 - 301),
+            Err(VerificationError::StaleTimestamp(1531420618))
+        );
+        assert_eq!(
+            verifier.verify_at("15314206.18", SIGNATURE, BODY.as_bytes(), 1531420618),
+            Err(VerificationError::InvalidTimestamp(
+                "15314206.18".to_string()
+            ))
+        );
+
+        let verifier = verifier.tolerance(Duration::from_secs(3600));
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, SIGNATURE, BODY.as_bytes(), 1531420618 + 301),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_format() {
+        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let now = 1531420618;
+        for signature in &[
+            "a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            "v1=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            "v0=not_hex",
+        ] {
+            assert_eq!(
+                verifier.verify_at(TIMESTAMP, signature, BODY.as_bytes(), now),
+                Err(VerificationError::InvalidSignature(signature.to_string()))
+            );
+        }
+        assert_eq!(
+            verifier.verify_at(TIMESTAMP, "v0=a2114d57", BODY.as_bytes(), now),
+            Err(VerificationError::SignatureMismatch)
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::composition::text::Text;
+use std::fmt;
+
+/// A Block Kit limit which a payload breaks.
+///
+/// Reference:
+/// https://api.slack.com/reference/block-kit/blocks
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// JSON path to the offending value, like `blocks[3].text.text`.
+    pub path: String,
+    pub kind: ViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViolationKind {
+    /// The string is longer than `max` characters.
+    TooLong { max: usize, actual: usize },
+    /// The array has more than `max` items.
+    TooMany { max: usize, actual: usize },
+    /// The number is greater than `max`.
+    TooLarge { max: usize, actual: usize },
+    /// The number is less than `min`.
+    TooSmall { min: usize, actual: usize },
+    /// The field is required, but not set.
+    Missing,
+    /// The object is set, but none of its fields are.
+    Empty,
+    /// The string is not a number, or not an integer when `integer` is true.
+    NotNumber { value: String, integer: bool },
+    /// The number in the string is greater than the number in `max`.
+    NumberTooLarge { max: String, actual: String },
+    /// The number in the string is less than the number in `min`.
+    NumberTooSmall { min: String, actual: String },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ViolationKind::TooLong { max, actual } => write!(
+                f,
+                "{}: must be at most {} characters, but was {}",
+                self.path, max, actual
+            ),
+            ViolationKind::TooMany { max, actual } => write!(
+                f,
+                "{}: must have at most {} items, but had {}",
+                self.path, max, actual
+            ),
+            ViolationKind::TooLarge { max, actual } => write!(
+                f,
+                "{}: must be at most {}, but was {}",
+                self.path, max, actual
+            ),
+            ViolationKind::NumberTooSmall { min, actual } => write!(
+                f,
+                "{}: must be at least {}, but was {}",
+                self.path, min, actual
+            ),
+            ViolationKind::TooSmall { min, actual } => write!(
+                f,
+                "{}: must be at least {}, but was {}",
+                self.path, min, actual
+            ),
+            ViolationKind::Missing => write!(f, "{}: is required", self.path),
+            ViolationKind::Empty => write!(f, "{}: must not be empty", self.path),
+            ViolationKind::NotNumber { value, integer } => write!(
+                f,
+                "{}: must be {}, but was `{}`",
+                self.path,
+                if *integer { "an integer" } else { "a number" },
+                value
+            ),
+            ViolationKind::NumberTooLarge { max, actual } => write!(
+                f,
+                "{}: must be at most {}, but was {}",
+                self.path, max, actual
+            ),
+        }
+    }
+}
+
+/// Checks the limits of Block Kit before sending payloads,
+/// because Slack only answers `invalid_blocks` for them.
+///
+/// # Example:
+/// ```rust
+/// use block_kit::block::section::SectionBlock;
+/// use block_kit::composition::text::Text::Plain;
+/// use block_kit::payload::message::CommonMessagePayload;
+/// use block_kit::validation::Validate;
+///
+/// let section = SectionBlock::new(Plain("a".repeat(3001).into()));
+/// let payload = CommonMessagePayload::new().blocks(vec![section.into()]);
+///
+/// let violations = payload.validate().unwrap_err();
+/// assert_eq!(violations[0].path, "blocks[0].text.text");
+/// ```
+pub trait Validate {
+    /// Appends violations found in `self`, which is placed at `path` of the payload.
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>);
+
+    fn validate(&self) -> Result<(), Vec<Violation>> {
+        let mut violations = vec![];
+        self.collect_violations("", &mut violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        if let Some(value) = self {
+            value.collect_violations(path, violations);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        for (i, value) in self.iter().enumerate() {
+            value.collect_violations(&index(path, i), violations);
+        }
+    }
+}
+
+pub(crate) fn field(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+pub(crate) fn index(path: &str, i: usize) -> String {
+    format!("{}[{}]", path, i)
+}
+
+pub(crate) fn check_length(path: String, value: &str, max: usize, violations: &mut Vec<Violation>) {
+    let actual = value.chars().count();
+    if actual > max {
+        violations.push(Violation {
+            path,
+            kind: ViolationKind::TooLong { max, actual },
+        });
+    }
+}
+
+pub(crate) fn check_optional_length(
+    path: String,
+    value: &Option<String>,
+    max: usize,
+    violations: &mut Vec<Violation>,
+) {
+    if let Some(value) = value {
+        check_length(path, value, max, violations);
+    }
+}
+
+/// Checks `text` of the text object at `path`.
+pub(crate) fn check_text(path: &str, text: &Text, max: usize, violations: &mut Vec<Violation>) {
+    check_length(field(path, "text"), text.as_str(), max, violations);
+}
+
+pub(crate) fn check_count<T>(
+    path: String,
+    values: &[T],
+    max: usize,
+    violations: &mut Vec<Violation>,
+) {
+    if values.len() > max {
+        violations.push(Violation {
+            path,
+            kind: ViolationKind::TooMany {
+                max,
+                actual: values.len(),
+            },
+        });
+    }
+}
+
+pub(crate) fn check_max(path: String, value: usize, max: usize, violations: &mut Vec<Violation>) {
+    if value > max {
+        violations.push(Violation {
+            path,
+            kind: ViolationKind::TooLarge { max, actual: value },
+        });
+    }
+}
+
+pub(crate) fn check_min(path: String, value: usize, min: usize, violations: &mut Vec<Violation>) {
+    if value < min {
+        violations.push(Violation {
+            path,
+            kind: ViolationKind::TooSmall { min, actual: value },
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(field("", "blocks"), "blocks");
+        assert_eq!(field("blocks[0]", "text"), "blocks[0].text");
+        assert_eq!(index("blocks", 3), "blocks[3]");
+    }
+
+    #[test]
+    fn test_check_length() {
+        let mut violations = vec![];
+        check_length("text".to_string(), "日本語", 3, &mut violations);
+        assert!(violations.is_empty());
+
+        check_length("text".to_string(), "日本語", 2, &mut violations);
+        assert_eq!(
+            violations,
+            vec![Violation {
+                path: "text".to_string(),
+                kind: ViolationKind::TooLong { max: 2, actual: 3 },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let violation = Violation {
+            path: "blocks".to_string(),
+            kind: ViolationKind::TooMany {
+                max: 50,
+                actual: 51,
+            },
+        };
+        assert_eq!(
+            violation.to_string(),
+            "blocks: must have at most 50 items, but had 51"
+        );
+    }
+}
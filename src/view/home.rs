@@ -0,0 +1,117 @@
+use crate::block::Block;
+use crate::validation::{check_count, check_optional_length, field, Validate, Violation};
+use crate::view::HOME_TYPE;
+use serde::{Deserialize, Serialize};
+
+/// The Home tab is a persistent space for each user in the App Home.
+/// Published with `ViewsPublishPayload`.
+///
+/// `blocks`: Maximum number of blocks is 100.
+///
+/// Reference:
+/// https://api.slack.com/reference/surfaces/views#home
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeView {
+    #[serde(rename = "type")]
+    type_name: String,
+    blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+}
+
+impl HomeView {
+    pub fn new(blocks: Vec<Block>) -> Self {
+        HomeView {
+            type_name: HOME_TYPE.to_string(),
+            blocks,
+            private_metadata: Option::default(),
+            callback_id: Option::default(),
+            external_id: Option::default(),
+        }
+    }
+
+    /// Sent back in `block_actions` payloads. Maximum length is 3000.
+    pub fn private_metadata(mut self, private_metadata: impl Into<String>) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    pub fn callback_id(mut self, callback_id: impl Into<String>) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+impl Validate for HomeView {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_count(field(path, "blocks"), &self.blocks, 100, violations);
+        self.blocks
+            .collect_violations(&field(path, "blocks"), violations);
+        check_optional_length(
+            field(path, "private_metadata"),
+            &self.private_metadata,
+            3000,
+            violations,
+        );
+        check_optional_length(
+            field(path, "callback_id"),
+            &self.callback_id,
+            255,
+            violations,
+        );
+        check_optional_length(
+            field(path, "external_id"),
+            &self.external_id,
+            255,
+            violations,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::header::HeaderBlock;
+
+    #[test]
+    fn test_ser_all() {
+        let home = HomeView::new(vec![HeaderBlock::new("dashboard").into()])
+            .private_metadata("metadata")
+            .callback_id("callback")
+            .external_id("external");
+        let json = serde_json::to_string_pretty(&home).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "home",
+  "blocks": [
+    {
+      "type": "header",
+      "text": {
+        "type": "plain_text",
+        "text": "dashboard"
+      }
+    }
+  ],
+  "private_metadata": "metadata",
+  "callback_id": "callback",
+  "external_id": "external"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let home = HomeView::new(vec![HeaderBlock::new("dashboard").into()]).callback_id("home");
+        let json = serde_json::to_string(&home).unwrap();
+        let decoded = serde_json::from_str::<HomeView>(&json).unwrap();
+        assert_eq!(decoded, home);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod home;
+pub mod modal;
+
+const HOME_TYPE: &str = "home";
+const MODAL_TYPE: &str = "modal";
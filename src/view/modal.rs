@@ -0,0 +1,275 @@
+use crate::block::Block;
+use crate::composition::text::Text::Plain;
+use crate::composition::text::{PlainText, Text};
+use crate::validation::{
+    check_count, check_optional_length, check_text, field, Validate, Violation, ViolationKind,
+};
+use crate::view::MODAL_TYPE;
+use serde::{Deserialize, Serialize};
+
+/// Modals are focused surfaces to collect data from users or display information.
+/// Opened with `ViewsOpenPayload` by a `trigger_id` of an interaction.
+///
+/// `title`, `submit` and `close`: Maximum length is 24 characters.
+/// `blocks`: Maximum number of blocks is 100.
+/// `submit` is required when `blocks` contain input blocks.
+///
+/// Reference:
+/// https://api.slack.com/reference/surfaces/views#modal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModalView {
+    #[serde(rename = "type")]
+    type_name: String,
+    title: Text,
+    blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    submit: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    clear_on_close: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify_on_close: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+}
+
+impl ModalView {
+    pub fn new(title: impl Into<PlainText>, blocks: Vec<Block>) -> Self {
+        ModalView {
+            type_name: MODAL_TYPE.to_string(),
+            title: Plain(title.into()),
+            blocks,
+            close: Option::default(),
+            submit: Option::default(),
+            private_metadata: Option::default(),
+            callback_id: Option::default(),
+            clear_on_close: Option::default(),
+            notify_on_close: Option::default(),
+            external_id: Option::default(),
+        }
+    }
+
+    pub fn close(mut self, close: impl Into<PlainText>) -> Self {
+        self.close = Some(Plain(close.into()));
+        self
+    }
+
+    pub fn submit(mut self, submit: impl Into<PlainText>) -> Self {
+        self.submit = Some(Plain(submit.into()));
+        self
+    }
+
+    /// Sent back in `view_submission` and `block_actions` payloads. Maximum length is 3000.
+    pub fn private_metadata(mut self, private_metadata: impl Into<String>) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    pub fn callback_id(mut self, callback_id: impl Into<String>) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    /// Closes all views in the view stack when the close button is clicked.
+    pub fn clear_on_close(mut self, clear_on_close: bool) -> Self {
+        self.clear_on_close = Some(clear_on_close);
+        self
+    }
+
+    /// Sends `view_closed` payload when the modal is closed.
+    pub fn notify_on_close(mut self, notify_on_close: bool) -> Self {
+        self.notify_on_close = Some(notify_on_close);
+        self
+    }
+
+    /// Unique among all views of the team, which can be used instead of `view_id`.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+impl Validate for ModalView {
+    fn collect_violations(&self, path: &str, violations: &mut Vec<Violation>) {
+        check_text(&field(path, "title"), &self.title, 24, violations);
+        check_count(field(path, "blocks"), &self.blocks, 100, violations);
+        self.blocks
+            .collect_violations(&field(path, "blocks"), violations);
+        if let Some(close) = &self.close {
+            check_text(&field(path, "close"), close, 24, violations);
+        }
+        match &self.submit {
+            Some(submit) => check_text(&field(path, "submit"), submit, 24, violations),
+            None if self
+                .blocks
+                .iter()
+                .any(|block| matches!(block, Block::Input(_))) =>
+            {
+                violations.push(Violation {
+                    path: field(path, "submit"),
+                    kind: ViolationKind::Missing,
+                })
+            }
+            None => {}
+        }
+        check_optional_length(
+            field(path, "private_metadata"),
+            &self.private_metadata,
+            3000,
+            violations,
+        );
+        check_optional_length(
+            field(path, "callback_id"),
+            &self.callback_id,
+            255,
+            violations,
+        );
+        check_optional_length(
+            field(path, "external_id"),
+            &self.external_id,
+            255,
+            violations,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::divider::DividerBlock;
+    use crate::block::input::InputBlock;
+    use crate::block::input::InputBlockElement::PlainTextInput;
+    use crate::block_element::plain_text_input::PlainTextInputElement;
+    use crate::validation::ViolationKind::{Missing, TooLong, TooMany};
+
+    #[test]
+    fn test_ser_new() {
+        let modal = ModalView::new("title", vec![DividerBlock::new().into()]);
+        let json = serde_json::to_string_pretty(&modal).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "modal",
+  "title": {
+    "type": "plain_text",
+    "text": "title"
+  },
+  "blocks": [
+    {
+      "type": "divider"
+    }
+  ]
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_ser_all() {
+        let input = InputBlock::new("label", PlainTextInput(PlainTextInputElement::new("a")));
+        let modal = ModalView::new("title", vec![input.into()])
+            .close("close")
+            .submit("submit")
+            .private_metadata("metadata")
+            .callback_id("callback")
+            .clear_on_close(true)
+            .notify_on_close(true)
+            .external_id("external");
+        let json = serde_json::to_string_pretty(&modal).unwrap_or("".to_string());
+        let expected = r#"{
+  "type": "modal",
+  "title": {
+    "type": "plain_text",
+    "text": "title"
+  },
+  "blocks": [
+    {
+      "type": "input",
+      "label": {
+        "type": "plain_text",
+        "text": "label"
+      },
+      "element": {
+        "type": "plain_text_input",
+        "action_id": "a"
+      }
+    }
+  ],
+  "close": {
+    "type": "plain_text",
+    "text": "close"
+  },
+  "submit": {
+    "type": "plain_text",
+    "text": "submit"
+  },
+  "private_metadata": "metadata",
+  "callback_id": "callback",
+  "clear_on_close": true,
+  "notify_on_close": true,
+  "external_id": "external"
+}"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let modal = ModalView::new("title", vec![DividerBlock::new().into()])
+            .submit("submit")
+            .private_metadata("metadata");
+        let json = serde_json::to_string(&modal).unwrap();
+        let decoded = serde_json::from_str::<ModalView>(&json).unwrap();
+        assert_eq!(decoded, modal);
+    }
+
+    #[test]
+    fn test_validate() {
+        let blocks = (0..101).map(|_| DividerBlock::new().into()).collect();
+        let modal = ModalView::new("a".repeat(25), blocks);
+        assert_eq!(
+            modal.validate(),
+            Err(vec![
+                Violation {
+                    path: "title.text".to_string(),
+                    kind: TooLong {
+                        max: 24,
+                        actual: 25
+                    },
+                },
+                Violation {
+                    path: "blocks".to_string(),
+                    kind: TooMany {
+                        max: 100,
+                        actual: 101
+                    },
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_submit() {
+        let input = InputBlock::new(
+            "label",
+            PlainTextInput(PlainTextInputElement::new("action_id")),
+        );
+        let modal = ModalView::new("title", vec![input.into()]);
+        let violations = modal.validate().unwrap_err();
+        assert_eq!(
+            violations,
+            vec![Violation {
+                path: "submit".to_string(),
+                kind: Missing,
+            }]
+        );
+        assert_eq!(violations[0].to_string(), "submit: is required");
+
+        let modal = modal.submit("submit");
+        assert_eq!(modal.validate(), Ok(()));
+
+        let modal = ModalView::new("title", vec![DividerBlock::new().into()]);
+        assert_eq!(modal.validate(), Ok(()));
+    }
+}